@@ -0,0 +1,241 @@
+//! # Anchors
+//!
+//! This pallet anchors document roots on chain so that other modules
+//! can verify proofs against them. Anchoring is a two step process:
+//!
+//! 1. An optional [pre_commit](struct.Module.html#method.pre_commit)
+//!    reserves an anchor id for its owner and records the signing root
+//!    of the document, until the pre-commit expires.
+//! 2. A [commit](struct.Module.html#method.commit) reveals the preimage
+//!    of the anchor id and stores the document root along with its owner
+//!    and an expiry block. When the anchor id was pre-committed, only the
+//!    pre-commit owner can commit, and the document root must be the hash
+//!    of the signing root and the given proof (the signature root).
+//!
+//! The owner of an anchor can [revoke](struct.Module.html#method.revoke_anchor)
+//! it, for instance when the document has been superseded, so it is no longer
+//! relied on.
+//!
+//! Other modules access anchors through the [AnchorProvider] trait.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, dispatch, ensure,
+    traits::Get,
+};
+use frame_system::ensure_signed;
+use sp_runtime::traits::Hash;
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub trait Trait: frame_system::Trait {
+    type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+    /// The number of blocks a pre-commit reserves an anchor id for its owner.
+    type PreCommitExpiration: Get<Self::BlockNumber>;
+}
+
+/// A reservation of an anchor id by its owner.
+#[derive(Encode, Decode, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct PreCommitData<Hash, AccountId, BlockNumber> {
+    /// Signing root of the document to be anchored.
+    pub signing_root: Hash,
+    /// The account that may commit the anchor.
+    pub owner: AccountId,
+    /// The block after which the reservation is no longer valid.
+    pub expiration_block: BlockNumber,
+}
+
+/// A committed document root.
+#[derive(Encode, Decode, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct AnchorData<Hash, AccountId, BlockNumber> {
+    /// Unique ID of the anchor, the hash of the preimage given at commit.
+    pub id: Hash,
+    /// Root hash of the anchored document.
+    pub doc_root: Hash,
+    /// The account that committed the anchor.
+    pub owner: AccountId,
+    /// The block after which the anchor should no longer be relied on.
+    pub expiration_block: BlockNumber,
+    /// Whether the owner has revoked the anchor, after which it should no longer be relied on.
+    pub revoked: bool,
+}
+
+/// An alias for the anchor data stored by this pallet.
+pub type AnchorDataFor<T> = AnchorData<
+    <T as frame_system::Trait>::Hash,
+    <T as frame_system::Trait>::AccountId,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
+/// A source of anchored document roots. A substrate module can implement this trait.
+pub trait AnchorProvider<Hash, AccountId, BlockNumber> {
+    /// Get the anchor with the given id, if it has been committed. Revoked anchors
+    /// are returned as well, with their `revoked` flag set.
+    fn get_anchor_by_id(anchor_id: Hash) -> Option<AnchorData<Hash, AccountId, BlockNumber>>;
+}
+
+decl_storage! {
+    trait Store for Module<T: Trait> as Anchor {
+        /// Pending reservations of anchor ids.
+        PreCommits get(fn get_pre_commit): map hasher(identity) T::Hash =>
+            Option<PreCommitData<T::Hash, T::AccountId, T::BlockNumber>>;
+        /// Committed anchors by id.
+        Anchors get(fn get_anchor): map hasher(identity) T::Hash => Option<AnchorDataFor<T>>;
+    }
+}
+
+decl_event!(
+    pub enum Event<T>
+    where
+        Hash      = <T as frame_system::Trait>::Hash,
+        AccountId = <T as frame_system::Trait>::AccountId,
+    {
+        /// An anchor id has been reserved by the account.
+        PreCommitted(Hash, AccountId),
+        /// A document root has been anchored by the account.
+        Anchored(Hash, AccountId),
+        /// An anchor has been revoked by its owner.
+        AnchorRevoked(Hash, AccountId),
+    }
+);
+
+decl_error! {
+    pub enum Error for Module<T: Trait> {
+        /// An anchor with the given id has already been committed.
+        AnchorExists,
+        /// The anchor id is reserved by an unexpired pre-commit of another account.
+        PreCommitExists,
+        /// Thrown when someone who is not the owner of a pre-commit attempts to commit it.
+        NotPreCommitOwner,
+        /// The document root is not the hash of the pre-committed signing root and the proof.
+        InvalidPreCommitProof,
+        /// The expiration block of an anchor must be in the future.
+        ExpirationInPast,
+        /// No anchor with the given id has been committed.
+        NonexistentAnchor,
+        /// Thrown when someone who is not the owner of an anchor attempts to revoke it.
+        NotAnchorOwner,
+        /// The anchor has already been revoked.
+        AnchorAlreadyRevoked,
+    }
+}
+
+decl_module! {
+    pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        type Error = Error<T>;
+        fn deposit_event() = default;
+
+        const PreCommitExpiration: T::BlockNumber = T::PreCommitExpiration::get();
+
+        /// Reserve an anchor id for the sender.
+        ///
+        /// - `anchor_id`: The id of the anchor that will be committed.
+        /// - `signing_root`: Signing root of the document to be anchored.
+        #[weight = 10_000]
+        pub fn pre_commit(origin, anchor_id: T::Hash, signing_root: T::Hash) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!<Anchors<T>>::contains_key(anchor_id), Error::<T>::AnchorExists);
+
+            let now = <frame_system::Module<T>>::block_number();
+            ensure!(
+                Self::get_pre_commit(anchor_id).map_or(true, |pre_commit| pre_commit.expiration_block < now),
+                Error::<T>::PreCommitExists
+            );
+
+            <PreCommits<T>>::insert(anchor_id, PreCommitData {
+                signing_root,
+                owner: who.clone(),
+                expiration_block: now + T::PreCommitExpiration::get(),
+            });
+
+            Self::deposit_event(RawEvent::PreCommitted(anchor_id, who));
+            Ok(())
+        }
+
+        /// Anchor a document root.
+        ///
+        /// - `anchor_id_preimage`: Preimage of the anchor id, which is its hash.
+        /// - `doc_root`: Root hash of the document.
+        /// - `proof`: The signature root of the document, checked against a pre-commit.
+        /// - `expiration_block`: The block after which the anchor expires.
+        #[weight = 10_000]
+        pub fn commit(origin,
+                      anchor_id_preimage: T::Hash,
+                      doc_root: T::Hash,
+                      proof: T::Hash,
+                      expiration_block: T::BlockNumber,
+        ) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            let anchor_id = T::Hashing::hash_of(&anchor_id_preimage);
+            ensure!(!<Anchors<T>>::contains_key(anchor_id), Error::<T>::AnchorExists);
+
+            let now = <frame_system::Module<T>>::block_number();
+            ensure!(expiration_block > now, Error::<T>::ExpirationInPast);
+
+            // An unexpired pre-commit binds the anchor id to its owner and signing root
+            if let Some(pre_commit) = Self::get_pre_commit(anchor_id).filter(|p| p.expiration_block >= now) {
+                ensure!(pre_commit.owner == who, Error::<T>::NotPreCommitOwner);
+                ensure!(
+                    doc_root == Self::hash_of(pre_commit.signing_root, proof),
+                    Error::<T>::InvalidPreCommitProof
+                );
+            }
+            <PreCommits<T>>::remove(anchor_id);
+
+            <Anchors<T>>::insert(anchor_id, AnchorData {
+                id: anchor_id,
+                doc_root,
+                owner: who.clone(),
+                expiration_block,
+                revoked: false,
+            });
+
+            Self::deposit_event(RawEvent::Anchored(anchor_id, who));
+            Ok(())
+        }
+
+        /// Revoke an anchor, so the anchored document root is no longer relied on.
+        ///
+        /// The dispatch origin for this call must be the anchor owner.
+        ///
+        /// - `anchor_id`: The id of the anchor to revoke.
+        #[weight = 10_000]
+        pub fn revoke_anchor(origin, anchor_id: T::Hash) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut anchor = Self::get_anchor(anchor_id).ok_or(Error::<T>::NonexistentAnchor)?;
+            ensure!(anchor.owner == who, Error::<T>::NotAnchorOwner);
+            ensure!(!anchor.revoked, Error::<T>::AnchorAlreadyRevoked);
+
+            anchor.revoked = true;
+            <Anchors<T>>::insert(anchor_id, anchor);
+
+            Self::deposit_event(RawEvent::AnchorRevoked(anchor_id, who));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Hash of the concatenation a ++ b.
+    fn hash_of(a: T::Hash, b: T::Hash) -> T::Hash {
+        let mut data: Vec<u8> = Vec::with_capacity(a.as_ref().len() + b.as_ref().len());
+        data.extend_from_slice(a.as_ref());
+        data.extend_from_slice(b.as_ref());
+        T::Hashing::hash(&data)
+    }
+}
+
+impl<T: Trait> AnchorProvider<T::Hash, T::AccountId, T::BlockNumber> for Module<T> {
+    fn get_anchor_by_id(anchor_id: T::Hash) -> Option<AnchorDataFor<T>> {
+        Self::get_anchor(anchor_id)
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{Error, AnchorProvider, mock::*};
+use sp_core::H256;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+// Anchor id for a preimage, along with a document root built from a signing root and proof.
+fn anchor_data(preimage: H256) -> (H256, H256, H256, H256) {
+    let anchor_id = BlakeTwo256::hash_of(&preimage);
+    let signing_root = H256::repeat_byte(1);
+    let proof = H256::repeat_byte(2);
+    let doc_root = BlakeTwo256::hash(&[signing_root.as_bytes(), proof.as_bytes()].concat());
+
+    (anchor_id, signing_root, proof, doc_root)
+}
+
+#[test]
+fn commit_without_pre_commit_works() {
+    new_test_ext().execute_with(|| {
+        let preimage = H256::repeat_byte(9);
+        let (anchor_id, _, proof, doc_root) = anchor_data(preimage);
+
+        assert_ok!(SUT::commit(Origin::signed(1), preimage, doc_root, proof, 100));
+
+        let anchor = SUT::get_anchor_by_id(anchor_id).unwrap();
+        assert_eq!(anchor.id, anchor_id);
+        assert_eq!(anchor.doc_root, doc_root);
+        assert_eq!(anchor.owner, 1);
+        assert_eq!(anchor.expiration_block, 100);
+    });
+}
+
+#[test]
+fn commit_existing_anchor_fails() {
+    new_test_ext().execute_with(|| {
+        let preimage = H256::repeat_byte(9);
+        let (_, _, proof, doc_root) = anchor_data(preimage);
+
+        assert_ok!(SUT::commit(Origin::signed(1), preimage, doc_root, proof, 100));
+        assert_noop!(
+            SUT::commit(Origin::signed(1), preimage, H256::zero(), proof, 100),
+            Error::<Test>::AnchorExists
+        );
+    });
+}
+
+#[test]
+fn commit_with_expiration_in_past_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(5);
+        let preimage = H256::repeat_byte(9);
+        let (_, _, proof, doc_root) = anchor_data(preimage);
+
+        assert_noop!(
+            SUT::commit(Origin::signed(1), preimage, doc_root, proof, 5),
+            Error::<Test>::ExpirationInPast
+        );
+    });
+}
+
+#[test]
+fn commit_after_pre_commit_works() {
+    new_test_ext().execute_with(|| {
+        let preimage = H256::repeat_byte(9);
+        let (anchor_id, signing_root, proof, doc_root) = anchor_data(preimage);
+
+        assert_ok!(SUT::pre_commit(Origin::signed(1), anchor_id, signing_root));
+        assert_eq!(SUT::get_pre_commit(anchor_id).unwrap().expiration_block, 10);
+
+        assert_ok!(SUT::commit(Origin::signed(1), preimage, doc_root, proof, 100));
+        assert_eq!(SUT::get_pre_commit(anchor_id), None);
+        assert_eq!(SUT::get_anchor_by_id(anchor_id).unwrap().doc_root, doc_root);
+    });
+}
+
+#[test]
+fn pre_commit_reserves_anchor_id() {
+    new_test_ext().execute_with(|| {
+        let preimage = H256::repeat_byte(9);
+        let (anchor_id, signing_root, proof, doc_root) = anchor_data(preimage);
+
+        assert_ok!(SUT::pre_commit(Origin::signed(1), anchor_id, signing_root));
+
+        assert_noop!(
+            SUT::pre_commit(Origin::signed(2), anchor_id, signing_root),
+            Error::<Test>::PreCommitExists
+        );
+        assert_noop!(
+            SUT::commit(Origin::signed(2), preimage, doc_root, proof, 100),
+            Error::<Test>::NotPreCommitOwner
+        );
+    });
+}
+
+#[test]
+fn commit_with_invalid_pre_commit_proof_fails() {
+    new_test_ext().execute_with(|| {
+        let preimage = H256::repeat_byte(9);
+        let (anchor_id, signing_root, _, doc_root) = anchor_data(preimage);
+
+        assert_ok!(SUT::pre_commit(Origin::signed(1), anchor_id, signing_root));
+        assert_noop!(
+            SUT::commit(Origin::signed(1), preimage, doc_root, H256::repeat_byte(3), 100),
+            Error::<Test>::InvalidPreCommitProof
+        );
+    });
+}
+
+#[test]
+fn expired_pre_commit_releases_anchor_id() {
+    new_test_ext().execute_with(|| {
+        let preimage = H256::repeat_byte(9);
+        let (anchor_id, signing_root, proof, doc_root) = anchor_data(preimage);
+
+        assert_ok!(SUT::pre_commit(Origin::signed(1), anchor_id, signing_root));
+        System::set_block_number(11);
+
+        assert_ok!(SUT::commit(Origin::signed(2), preimage, doc_root, proof, 100));
+        assert_eq!(SUT::get_anchor_by_id(anchor_id).unwrap().owner, 2);
+    });
+}
+
+#[test]
+fn revoke_anchor_works() {
+    new_test_ext().execute_with(|| {
+        let preimage = H256::repeat_byte(9);
+        let (anchor_id, _, proof, doc_root) = anchor_data(preimage);
+
+        assert_noop!(
+            SUT::revoke_anchor(Origin::signed(1), anchor_id),
+            Error::<Test>::NonexistentAnchor
+        );
+        assert_ok!(SUT::commit(Origin::signed(1), preimage, doc_root, proof, 100));
+        assert!(!SUT::get_anchor_by_id(anchor_id).unwrap().revoked);
+
+        assert_noop!(
+            SUT::revoke_anchor(Origin::signed(2), anchor_id),
+            Error::<Test>::NotAnchorOwner
+        );
+        assert_ok!(SUT::revoke_anchor(Origin::signed(1), anchor_id));
+        assert!(SUT::get_anchor_by_id(anchor_id).unwrap().revoked);
+        assert_noop!(
+            SUT::revoke_anchor(Origin::signed(1), anchor_id),
+            Error::<Test>::AnchorAlreadyRevoked
+        );
+    });
+}
@@ -12,8 +12,10 @@
 use codec::{Decode, Encode, FullCodec};
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, dispatch, ensure,
+    storage::{generator::StorageDoubleMap as StorageDoubleMapGenerator, StorageDoubleMap},
     traits::{EnsureOrigin, Get},
-    Hashable,
+    weights::Weight,
+    Hashable, IterableStorageDoubleMap,
 };
 use frame_system::ensure_signed;
 use sp_runtime::{
@@ -28,6 +30,8 @@ use sp_std::{
 
 use unique_assets::traits::*;
 
+mod migration;
+
 #[cfg(test)]
 mod mock;
 
@@ -41,12 +45,20 @@ pub trait InRegistry {
 }
 
 pub trait Trait<I = DefaultInstance>: frame_system::Trait /* Mintable<Self as frame_system::Trait> */ {
+    /// The dispatch origin that is able to mint new instances of this type of commodity.
+    type CommodityAdmin: EnsureOrigin<Self::Origin>;
     /// The data type that is used to describe this type of commodity.
     type CommodityInfo: Hashable + Member + Debug + Default + FullCodec + InRegistry;
     /// The maximum number of this type of commodity that may exist (minted - burned).
     type CommodityLimit: Get<u128>;
     /// The maximum number of this type of commodity that any single account may own.
     type UserCommodityLimit: Get<u64>;
+    /// How the ids of newly minted commodities are derived.
+    type IdStrategy: CommodityIdStrategy<Self, <Self as Trait<I>>::CommodityInfo>;
+    /// Checks and accounting of other pallets on every transfer of a commodity.
+    type TransferHook: TransferHook<Self::AccountId, CommodityId<Self>>;
+    /// Checks and accounting of other pallets on every burn of a commodity.
+    type BurnHook: BurnHook<Self::AccountId, CommodityId<Self>>;
     type Event: From<Event<Self, I>> + Into<<Self as frame_system::Trait>::Event>;
 }
 
@@ -68,7 +80,7 @@ impl<CommodityId, CommodityInfo> Nft for Commodity<CommodityId, CommodityInfo> {
     type Info = CommodityInfo;
 }
 
-// Needed to maintain a sorted list.
+// Commodities are ordered by their ids.
 impl<CommodityId: Ord, CommodityInfo: Eq> Ord for Commodity<CommodityId, CommodityInfo> {
     fn cmp(&self, other: &Self) -> Ordering {
         self.id.cmp(&other.id)
@@ -87,38 +99,151 @@ impl<CommodityId: Eq, CommodityInfo> PartialEq for Commodity<CommodityId, Commod
     }
 }
 
+/// Derives the id of a newly minted commodity.
+pub trait CommodityIdStrategy<T: frame_system::Trait, CommodityInfo> {
+    /// The id of the commodity defined by `info`, which is the `index`-th commodity
+    /// minted by this pallet instance (starting from 0, including burned commodities).
+    fn commodity_id(info: &CommodityInfo, index: u64) -> CommodityId<T>;
+}
+
+/// Identify a commodity by the hash of its info. Commodities with identical info
+/// can't both exist.
+pub struct ContentHash;
+
+impl<T: frame_system::Trait, CommodityInfo: Encode> CommodityIdStrategy<T, CommodityInfo> for ContentHash {
+    fn commodity_id(info: &CommodityInfo, _index: u64) -> CommodityId<T> {
+        T::Hashing::hash_of(info)
+    }
+}
+
+/// Identify a commodity by the hash of its info and its mint index, so any number of
+/// commodities may have identical info.
+pub struct ContentHashWithNonce;
+
+impl<T: frame_system::Trait, CommodityInfo: Encode> CommodityIdStrategy<T, CommodityInfo> for ContentHashWithNonce {
+    fn commodity_id(info: &CommodityInfo, index: u64) -> CommodityId<T> {
+        T::Hashing::hash_of(&(info, index))
+    }
+}
+
+/// Identify a commodity by its mint index, big-endian in the last bytes of the id, so
+/// ids are ordered by mint.
+pub struct Sequential;
+
+impl<T: frame_system::Trait, CommodityInfo> CommodityIdStrategy<T, CommodityInfo> for Sequential {
+    fn commodity_id(_info: &CommodityInfo, index: u64) -> CommodityId<T> {
+        let mut id = CommodityId::<T>::default();
+        let bytes = id.as_mut();
+        let len = bytes.len();
+        bytes[len - 8..].copy_from_slice(&index.to_be_bytes());
+        id
+    }
+}
+
+/// A hook into transfers of commodities, for pallets that restrict or keep their own
+/// accounting of them.
+pub trait TransferHook<AccountId, CommodityId> {
+    /// Check whether a commodity may be transferred from its owner `from` to `to`.
+    fn can_transfer(from: &AccountId, to: &AccountId, commodity_id: &CommodityId) -> dispatch::DispatchResult;
+    /// Called after a commodity has been transferred from `from` to `to`.
+    fn on_transfer(from: &AccountId, to: &AccountId, commodity_id: &CommodityId);
+}
+
+impl<AccountId, CommodityId> TransferHook<AccountId, CommodityId> for () {
+    fn can_transfer(_from: &AccountId, _to: &AccountId, _commodity_id: &CommodityId) -> dispatch::DispatchResult {
+        Ok(())
+    }
+
+    fn on_transfer(_from: &AccountId, _to: &AccountId, _commodity_id: &CommodityId) {}
+}
+
+/// A hook into burns of commodities, for pallets that restrict or keep their own
+/// accounting of them.
+pub trait BurnHook<AccountId, CommodityId> {
+    /// Check whether `owner` may burn its commodity through the `burn` call of this pallet.
+    fn can_burn(owner: &AccountId, commodity_id: &CommodityId) -> dispatch::DispatchResult;
+    /// Called before a commodity of `owner` is burned, while its info is still stored.
+    fn on_burn(owner: &AccountId, commodity_id: &CommodityId);
+}
+
+impl<AccountId, CommodityId> BurnHook<AccountId, CommodityId> for () {
+    fn can_burn(_owner: &AccountId, _commodity_id: &CommodityId) -> dispatch::DispatchResult {
+        Ok(())
+    }
+
+    fn on_burn(_owner: &AccountId, _commodity_id: &CommodityId) {}
+}
+
+/// Storage layouts of this pallet, used to run migrations on a runtime upgrade.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+    /// Commodities are stored in a sorted `Vec` per account.
+    V1_0_0,
+    /// Ownership is stored in a double map, and commodity info in a map by id.
+    V2_0_0,
+}
+
+impl Default for Releases {
+    fn default() -> Self {
+        Releases::V1_0_0
+    }
+}
+
 decl_storage! {
     trait Store for Module<T: Trait<I>, I: Instance = DefaultInstance> as Commodity {
         /// The total number of this type of commodity that exists (minted - burned).
         Total get(fn total): u128 = 0;
         /// The total number of this type of commodity that has been burned (may overflow).
         Burned get(fn burned): u128 = 0;
+        /// The number of commodities of this type that have been minted, used to derive their ids.
+        MintNonce get(fn mint_nonce): u64;
         /// The total number of this type of commodity owned by an account.
         TotalForAccount get(fn total_for_account): map hasher(blake2_128_concat) T::AccountId => u64 = 0;
-        /// A mapping from an account to a list of all of the commodities of this type that are owned by it.
-        CommoditiesForAccount get(fn commodities_for_account): map hasher(blake2_128_concat) T::AccountId => Vec<CommodityFor<T, I>>;
+        /// The ids of all of the commodities of this type that are owned by an account.
+        OwnedCommodities: double_map hasher(blake2_128_concat) T::AccountId, hasher(identity) CommodityId<T> => ();
+        /// A mapping from a commodity ID to the info that defines it.
+        CommodityInfoOf get(fn commodity_info): map hasher(identity) CommodityId<T> => Option<<T as Trait<I>>::CommodityInfo>;
         /// A mapping from a commodity ID to the account that owns it.
-        AccountForCommodity get(fn account_for_commodity): map hasher(identity) CommodityId<T> => T::AccountId;
+        AccountForCommodity get(fn account_for_commodity): map hasher(identity) CommodityId<T> => Option<T::AccountId>;
+        /// The account approved to transfer a commodity on behalf of its owner, until the commodity
+        /// is transferred.
+        Approvals get(fn approved): map hasher(identity) CommodityId<T> => Option<T::AccountId>;
+        /// Operators approved by an owner to transfer and approve all of its commodities.
+        Operators get(fn is_approved_for_all): double_map hasher(blake2_128_concat) T::AccountId, hasher(blake2_128_concat) T::AccountId => bool;
+        /// The storage layout version, for migrations. New chains start at the latest layout.
+        StorageVersion build(|_| Releases::V2_0_0): Releases;
     }
 }
 
-// Empty event to satisfy type constraints
 decl_event!(
     pub enum Event<T, I = DefaultInstance>
     where
         CommodityId = <T as frame_system::Trait>::Hash,
+        AccountId = <T as frame_system::Trait>::AccountId,
     {
-        Tmp(CommodityId),
+        /// A commodity has been minted to its owner. [commodity_id, owner]
+        Minted(CommodityId, AccountId),
+        /// Ownership of a commodity has changed. [commodity_id, from, to]
+        Transferred(CommodityId, AccountId, AccountId),
+        /// A commodity has been destroyed. [commodity_id, owner]
+        Burned(CommodityId, AccountId),
+        /// The approved account of a commodity has been set, or cleared when `None`.
+        /// [commodity_id, owner, approved]
+        Approval(CommodityId, AccountId, Option<AccountId>),
+        /// An operator has been approved or unapproved for all commodities of an owner.
+        /// [owner, operator, approved]
+        ApprovalForAll(AccountId, AccountId, bool),
     }
 );
 
 decl_error! {
     pub enum Error for Module<T: Trait<I>, I: Instance> {
-        // Thrown when there is an attempt to mint a duplicate commodity.
+        // Thrown when there is an attempt to mint a commodity with the id of an existing one,
+        // which only happens when commodities are identified by the hash of their info.
         CommodityExists,
         // Thrown when there is an attempt to burn or transfer a nonexistent commodity.
         NonexistentCommodity,
-        // Thrown when someone who is not the owner of a commodity attempts to transfer or burn it.
+        // Thrown when someone who is not the owner of a commodity attempts to burn it.
         NotCommodityOwner,
         // Thrown when the commodity admin attempts to mint a commodity and the maximum number of this
         // type of commodity already exists.
@@ -126,14 +251,238 @@ decl_error! {
         // Thrown when an attempt is made to mint or transfer a commodity to an account that already
         // owns the maximum number of this type of commodity.
         TooManyCommoditiesForAccount,
+        // Thrown when an account that is not the owner, approved or an operator of a commodity
+        // attempts to transfer or approve it.
+        NotApproved,
     }
 }
 
-// Empty module so that storage can be declared
 decl_module! {
     pub struct Module<T: Trait<I>, I: Instance = DefaultInstance> for enum Call where origin: T::Origin {
         type Error = Error<T, I>;
         fn deposit_event() = default;
+
+        /// Create a new commodity from the provided commodity info, with an id derived by
+        /// `T::IdStrategy`.
+        ///
+        /// The dispatch origin for this call must be the commodity admin.
+        ///
+        /// This function will throw an error if the maximum number of this type of commodity
+        /// already exists, or if the new owner already owns the maximum number of this type
+        /// of commodity. With the `ContentHash` strategy, it will also throw an error if it is
+        /// called with commodity info that describes an existing (duplicate) commodity.
+        ///
+        /// - `owner_account`: Receiver of the commodity.
+        /// - `commodity_info`: The information that defines the commodity.
+        #[weight = 10_000]
+        pub fn mint(origin, owner_account: T::AccountId, commodity_info: <T as Trait<I>>::CommodityInfo) -> dispatch::DispatchResult {
+            T::CommodityAdmin::ensure_origin(origin)?;
+
+            <Self as Mintable>::mint(&owner_account, commodity_info)?;
+            Ok(())
+        }
+
+        /// Destroy a commodity.
+        ///
+        /// The dispatch origin for this call must be the commodity owner.
+        ///
+        /// - `commodity_id`: The id of the commodity to destroy, as derived by `T::IdStrategy`.
+        #[weight = 10_000]
+        pub fn burn(origin, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::owner_of(&commodity_id) == Some(who.clone()), Error::<T, I>::NotCommodityOwner);
+            T::BurnHook::can_burn(&who, &commodity_id)?;
+
+            <Self as Burnable>::burn(&commodity_id)
+        }
+
+        /// Transfer a commodity to a new owner.
+        ///
+        /// The dispatch origin for this call must be the commodity owner, the account
+        /// approved for the commodity, or an operator of the owner. Transferring clears
+        /// the approved account.
+        ///
+        /// This function will throw an error if the new owner already owns the maximum
+        /// number of this type of commodity.
+        ///
+        /// - `dest_account`: Receiver of the commodity.
+        /// - `commodity_id`: The id of the commodity to transfer, as derived by `T::IdStrategy`.
+        #[weight = 10_000]
+        pub fn transfer(origin, dest_account: T::AccountId, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_transfer_from(&who, &dest_account, &commodity_id)
+        }
+
+        /// Transfer a commodity to a new owner on behalf of its owner.
+        ///
+        /// The dispatch origin for this call must be the commodity owner, the account
+        /// approved for the commodity, or an operator of the owner. Transferring clears
+        /// the approved account.
+        ///
+        /// - `dest_account`: Receiver of the commodity.
+        /// - `commodity_id`: The id of the commodity to transfer, as derived by `T::IdStrategy`.
+        #[weight = 10_000]
+        pub fn transfer_from(origin, dest_account: T::AccountId, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_transfer_from(&who, &dest_account, &commodity_id)
+        }
+
+        /// Approve an account to transfer a commodity, or clear the approval with `None`.
+        ///
+        /// The dispatch origin for this call must be the commodity owner or an operator
+        /// of the owner.
+        ///
+        /// - `approved`: The account to approve, if any.
+        /// - `commodity_id`: The id of the commodity to approve the account for.
+        #[weight = 10_000]
+        pub fn approve(origin, approved: Option<T::AccountId>, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_approve(&who, approved, &commodity_id)
+        }
+
+        /// Approve or unapprove an operator to transfer and approve all commodities
+        /// of the sender.
+        ///
+        /// - `operator`: The account to approve or unapprove.
+        /// - `approved`: Whether the operator is approved.
+        #[weight = 10_000]
+        pub fn set_approval_for_all(origin, operator: T::AccountId, approved: bool) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_set_approval_for_all(&who, &operator, approved);
+            Ok(())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::<I>::get() == Releases::V1_0_0 {
+                StorageVersion::<I>::put(Releases::V2_0_0);
+                migration::migrate_to_double_map::<T, I>()
+                    .saturating_add(T::DbWeight::get().reads_writes(1, 1))
+            } else {
+                T::DbWeight::get().reads(1)
+            }
+        }
+    }
+}
+
+impl<T: Trait<I>, I: Instance> Module<T, I> {
+    /// All of the commodities of this type owned by an account, ordered by id.
+    pub fn commodities_for_account(account: &T::AccountId) -> Vec<CommodityFor<T, I>> {
+        OwnedCommodities::<T, I>::iter_prefix(account)
+            .filter_map(|(id, ())| {
+                Self::commodity_info(&id).map(|commodity| Commodity { id, commodity })
+            })
+            .collect()
+    }
+
+    /// Up to `limit` of the commodities of this type owned by an account, in order of id,
+    /// starting after the commodity id `start_after` when given.
+    pub fn commodities_for_account_page(account: &T::AccountId,
+                                        start_after: Option<CommodityId<T>>,
+                                        limit: u32,
+    ) -> Vec<CommodityFor<T, I>> {
+        let prefix = <OwnedCommodities<T, I> as StorageDoubleMapGenerator<_, _, _>>::storage_double_map_final_key1(account);
+        // Continue from the key of the cursor instead of reading every commodity before it
+        let mut key = match start_after {
+            Some(start) => OwnedCommodities::<T, I>::hashed_key_for(account, start),
+            None => prefix.clone(),
+        };
+
+        let mut commodities = Vec::new();
+        while commodities.len() < limit as usize {
+            match sp_io::storage::next_key(&key).filter(|next| next.starts_with(&prefix)) {
+                Some(next) => {
+                    // Commodity ids are identity hashed, so the keys end with the id in order of id
+                    if let Ok(id) = CommodityId::<T>::decode(&mut &next[prefix.len()..]) {
+                        if let Some(commodity) = Self::commodity_info(&id) {
+                            commodities.push(Commodity { id, commodity });
+                        }
+                    }
+                    key = next;
+                }
+                None => break,
+            }
+        }
+        commodities
+    }
+
+    /// The owner of a commodity, if it exists.
+    pub fn owner_of(commodity_id: &CommodityId<T>) -> Option<T::AccountId> {
+        Self::account_for_commodity(commodity_id)
+    }
+
+    /// The registry a commodity belongs to, if it exists.
+    pub fn registry_of(commodity_id: &CommodityId<T>) -> Option<RegistryId> {
+        Self::commodity_info(commodity_id).map(|info| info.registry_id())
+    }
+
+    /// Whether an account may transfer a commodity, as its owner, its approved account
+    /// or an operator of its owner.
+    pub fn is_approved_or_owner(who: &T::AccountId, commodity_id: &CommodityId<T>) -> bool {
+        match Self::owner_of(commodity_id) {
+            Some(owner) => {
+                owner == *who
+                    || Self::approved(commodity_id).as_ref() == Some(who)
+                    || Self::is_approved_for_all(&owner, who)
+            }
+            None => false,
+        }
+    }
+
+    /// Approve an account to transfer a commodity, or clear the approval with `None`.
+    ///
+    /// Only the owner of the commodity or an operator of the owner can approve.
+    pub fn do_approve(
+        who: &T::AccountId,
+        approved: Option<T::AccountId>,
+        commodity_id: &CommodityId<T>,
+    ) -> dispatch::DispatchResult {
+        let owner = Self::owner_of(commodity_id).ok_or(Error::<T, I>::NonexistentCommodity)?;
+        ensure!(
+            owner == *who || Self::is_approved_for_all(&owner, who),
+            Error::<T, I>::NotApproved
+        );
+
+        match &approved {
+            Some(account) => Approvals::<T, I>::insert(commodity_id, account),
+            None => Approvals::<T, I>::remove(commodity_id),
+        }
+
+        Self::deposit_event(Event::<T, I>::Approval(*commodity_id, owner, approved));
+        Ok(())
+    }
+
+    /// Approve or unapprove an operator to transfer and approve all commodities of the owner.
+    pub fn do_set_approval_for_all(owner: &T::AccountId, operator: &T::AccountId, approved: bool) {
+        if approved {
+            Operators::<T, I>::insert(owner, operator, true);
+        } else {
+            Operators::<T, I>::remove(owner, operator);
+        }
+
+        Self::deposit_event(Event::<T, I>::ApprovalForAll(owner.clone(), operator.clone(), approved));
+    }
+
+    /// Transfer a commodity on behalf of its owner. `who` must be the owner, the approved
+    /// account or an operator of the owner.
+    pub fn do_transfer_from(
+        who: &T::AccountId,
+        dest_account: &T::AccountId,
+        commodity_id: &CommodityId<T>,
+    ) -> dispatch::DispatchResult {
+        ensure!(
+            Self::owner_of(commodity_id).is_some(),
+            Error::<T, I>::NonexistentCommodity
+        );
+        ensure!(
+            Self::is_approved_or_owner(who, commodity_id),
+            Error::<T, I>::NotApproved
+        );
+
+        <Self as Unique>::transfer(dest_account, commodity_id)
     }
 }
 
@@ -159,46 +508,36 @@ impl<T: Trait<I>, I: Instance>
         Self::commodities_for_account(account)
     }
 
+    /// The owner of a commodity. The trait can't express a nonexistent commodity, so one is
+    /// reported as owned by the default account, which may also own real commodities.
+    /// Callers must check that the commodity exists first, or use the inherent `owner_of`,
+    /// which returns `None` for a nonexistent commodity.
     fn owner_of(commodity_id: &CommodityId<T>) -> T::AccountId {
-        Self::account_for_commodity(commodity_id)
+        Self::account_for_commodity(commodity_id).unwrap_or_default()
     }
 
     fn transfer(
         dest_account: &T::AccountId,
         commodity_id: &CommodityId<T>,
     ) -> dispatch::DispatchResult {
-        let owner = Self::owner_of(&commodity_id);
-        ensure!(
-            owner != T::AccountId::default(),
-            Error::<T, I>::NonexistentCommodity
-        );
+        let owner = Self::owner_of(commodity_id).ok_or(Error::<T, I>::NonexistentCommodity)?;
 
         ensure!(
             Self::total_for_account(dest_account) < T::UserCommodityLimit::get(),
             Error::<T, I>::TooManyCommoditiesForAccount
         );
 
-        let xfer_commodity = Commodity::<CommodityId<T>, <T as Trait<I>>::CommodityInfo> {
-            id: *commodity_id,
-            commodity: <T as Trait<I>>::CommodityInfo::default(),
-        };
+        T::TransferHook::can_transfer(&owner, dest_account, commodity_id)?;
 
         TotalForAccount::<T, I>::mutate(&owner, |total| *total -= 1);
         TotalForAccount::<T, I>::mutate(dest_account, |total| *total += 1);
-        let commodity = CommoditiesForAccount::<T, I>::mutate(owner, |commodities| {
-            let pos = commodities
-                .binary_search(&xfer_commodity)
-                .expect("We already checked that we have the correct owner; qed");
-            commodities.remove(pos)
-        });
-        CommoditiesForAccount::<T, I>::mutate(dest_account, |commodities| {
-            match commodities.binary_search(&commodity) {
-                Ok(_pos) => {} // should never happen
-                Err(pos) => commodities.insert(pos, commodity),
-            }
-        });
+        OwnedCommodities::<T, I>::remove(&owner, commodity_id);
+        OwnedCommodities::<T, I>::insert(dest_account, commodity_id, ());
+        Approvals::<T, I>::remove(commodity_id);
         AccountForCommodity::<T, I>::insert(&commodity_id, &dest_account);
+        T::TransferHook::on_transfer(&owner, dest_account, commodity_id);
 
+        Self::deposit_event(Event::<T, I>::Transferred(*commodity_id, owner, dest_account.clone()));
         Ok(())
     }
 }
@@ -213,7 +552,8 @@ impl<T: Trait<I>, I: Instance>
         owner_account: &T::AccountId,
         commodity_info: <T as Trait<I>>::CommodityInfo,
     ) -> dispatch::result::Result<CommodityId<T>, dispatch::DispatchError> {
-        let commodity_id = T::Hashing::hash_of(&commodity_info);
+        let index = Self::mint_nonce();
+        let commodity_id = T::IdStrategy::commodity_id(&commodity_info, index);
 
         ensure!(
             !AccountForCommodity::<T, I>::contains_key(&commodity_id),
@@ -230,21 +570,16 @@ impl<T: Trait<I>, I: Instance>
             Error::<T, I>::TooManyCommodities
         );
 
-        let new_commodity = Commodity {
-            id: commodity_id,
-            commodity: commodity_info,
-        };
+        let next_index = index.checked_add(1).ok_or("Overflow when updating the mint nonce.")?;
 
+        MintNonce::<I>::put(next_index);
         Total::<I>::mutate(|total| *total += 1);
         TotalForAccount::<T, I>::mutate(owner_account, |total| *total += 1);
-        CommoditiesForAccount::<T, I>::mutate(owner_account, |commodities| {
-            match commodities.binary_search(&new_commodity) {
-                Ok(_pos) => {} // should never happen
-                Err(pos) => commodities.insert(pos, new_commodity),
-            }
-        });
+        OwnedCommodities::<T, I>::insert(owner_account, commodity_id, ());
+        CommodityInfoOf::<T, I>::insert(commodity_id, commodity_info);
         AccountForCommodity::<T, I>::insert(commodity_id, &owner_account);
 
+        Self::deposit_event(Event::<T, I>::Minted(commodity_id, owner_account.clone()));
         Ok(commodity_id)
     }
 }
@@ -260,28 +595,18 @@ impl<T: Trait<I>, I: Instance>
     }
 
     fn burn(commodity_id: &CommodityId<T>) -> dispatch::DispatchResult {
-        let owner = Self::owner_of(commodity_id);
-        ensure!(
-            owner != T::AccountId::default(),
-            Error::<T, I>::NonexistentCommodity
-        );
-
-        let burn_commodity = Commodity::<CommodityId<T>, <T as Trait<I>>::CommodityInfo> {
-            id: *commodity_id,
-            commodity: <T as Trait<I>>::CommodityInfo::default(),
-        };
+        let owner = Self::owner_of(commodity_id).ok_or(Error::<T, I>::NonexistentCommodity)?;
 
+        T::BurnHook::on_burn(&owner, commodity_id);
         Total::<I>::mutate(|total| *total -= 1);
         Burned::<I>::mutate(|total| *total += 1);
         TotalForAccount::<T, I>::mutate(&owner, |total| *total -= 1);
-        CommoditiesForAccount::<T, I>::mutate(owner, |commodities| {
-            let pos = commodities
-                .binary_search(&burn_commodity)
-                .expect("We already checked that we have the correct owner; qed");
-            commodities.remove(pos);
-        });
+        OwnedCommodities::<T, I>::remove(&owner, commodity_id);
+        CommodityInfoOf::<T, I>::remove(commodity_id);
+        Approvals::<T, I>::remove(commodity_id);
         AccountForCommodity::<T, I>::remove(&commodity_id);
 
+        Self::deposit_event(Event::<T, I>::Burned(*commodity_id, owner));
         Ok(())
     }
 }
@@ -0,0 +1,36 @@
+//! Storage migrations of the commodities pallet.
+
+use crate::{
+    AccountForCommodity, CommodityFor, CommodityInfoOf, Instance, OwnedCommodities, Trait,
+};
+use frame_support::{storage::migration::StorageIterator, traits::Get, weights::Weight};
+use sp_std::vec::Vec;
+
+/// Move the sorted `Vec` of commodities of every account in the old `CommoditiesForAccount`
+/// map into the `OwnedCommodities` double map and the `CommodityInfoOf` map.
+///
+/// The whole map is migrated in the block of the upgrade, and the returned weight grows
+/// with the number of accounts and commodities: one read and one removal per account,
+/// and one read and two writes per commodity. It is only suitable for chains with few
+/// enough commodities for that weight to fit in a single block.
+pub fn migrate_to_double_map<T: Trait<I>, I: Instance>() -> Weight {
+    // Drain the old map before writing, so the iterator isn't invalidated by the inserts
+    let accounts: Vec<Vec<CommodityFor<T, I>>> =
+        StorageIterator::<Vec<CommodityFor<T, I>>>::new(I::PREFIX.as_bytes(), b"CommoditiesForAccount")
+            .drain()
+            .map(|(_key, commodities)| commodities)
+            .collect();
+
+    let mut reads: Weight = accounts.len() as Weight;
+    let mut writes: Weight = accounts.len() as Weight;
+    for commodity in accounts.into_iter().flatten() {
+        reads += 1;
+        if let Some(owner) = AccountForCommodity::<T, I>::get(&commodity.id) {
+            OwnedCommodities::<T, I>::insert(&owner, &commodity.id, ());
+            CommodityInfoOf::<T, I>::insert(&commodity.id, commodity.commodity);
+            writes += 2;
+        }
+    }
+
+    T::DbWeight::get().reads_writes(reads, writes)
+}
@@ -1,7 +1,7 @@
 // Creating mock runtime here
 
-use crate::{Module, Trait};
-use frame_support::{impl_outer_origin, parameter_types, weights::Weight};
+use crate::{ContentHash, Module, Trait};
+use frame_support::{impl_outer_event, impl_outer_origin, parameter_types, weights::Weight};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
@@ -14,6 +14,17 @@ impl_outer_origin! {
     pub enum Origin for Test where system = frame_system {}
 }
 
+mod nft {
+    pub use crate::Event;
+}
+
+impl_outer_event! {
+    pub enum TestEvent for Test {
+        system<T>,
+        nft<T>,
+    }
+}
+
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub const MaximumBlockWeight: Weight = 1024;
@@ -32,7 +43,7 @@ impl system::Trait for Test {
     type AccountId = u64;
     type Lookup = IdentityLookup<Self::AccountId>;
     type Header = Header;
-    type Event = ();
+    type Event = TestEvent;
     type BlockHashCount = BlockHashCount;
     type MaximumBlockWeight = MaximumBlockWeight;
     type DbWeight = ();
@@ -61,13 +72,18 @@ parameter_types! {
 pub struct Test;
 
 impl Trait for Test {
-    type Event = ();
-    //type CommodityAdmin = frame_system::EnsureRoot<Self::AccountId>;
+    type Event = TestEvent;
+    type CommodityAdmin = frame_system::EnsureRoot<Self::AccountId>;
     type CommodityInfo = Vec<u8>;
     type CommodityLimit = MaxCommodities;
     type UserCommodityLimit = MaxCommoditiesPerUser;
+    type IdStrategy = ContentHash;
+    type TransferHook = ();
+    type BurnHook = ();
 }
 
+pub type System = system::Module<Test>;
+
 // system under test
 pub type SUT = Module<Test>;
 
@@ -1,7 +1,12 @@
 use crate::mock::*;
 use crate::unique_assets::traits::*;
 use crate::*;
-use frame_support::{assert_err, assert_ok, Hashable};
+use frame_support::{
+    assert_err, assert_ok,
+    storage::migration::{put_storage_value, StorageIterator},
+    traits::OnRuntimeUpgrade,
+    Hashable,
+};
 use sp_core::H256;
 
 #[test]
@@ -9,22 +14,22 @@ fn mint() {
     new_test_ext().execute_with(|| {
         assert_eq!(SUT::total(), 0);
         assert_eq!(SUT::total_for_account(1), 0);
-        assert_eq!(<SUT as UniqueAssets<_>>::total(), 0);
-        assert_eq!(<SUT as UniqueAssets<_>>::total_for_account(&1), 0);
+        assert_eq!(<SUT as Unique>::total(), 0);
+        assert_eq!(<SUT as Unique>::total_for_account(&1), 0);
         assert_eq!(
             SUT::account_for_commodity::<H256>(Vec::<u8>::default().blake2_256().into()),
-            0
+            None
         );
 
         assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
 
         assert_eq!(SUT::total(), 1);
-        assert_eq!(<SUT as UniqueAssets<_>>::total(), 1);
+        assert_eq!(<SUT as Unique>::total(), 1);
         assert_eq!(SUT::burned(), 0);
-        assert_eq!(<SUT as Burnable<_>>::burned(), 0);
+        assert_eq!(<SUT as Burnable>::burned(), 0);
         assert_eq!(SUT::total_for_account(1), 1);
-        assert_eq!(<SUT as UniqueAssets<_>>::total_for_account(&1), 1);
-        let commodities_for_account = SUT::commodities_for_account::<u64>(1);
+        assert_eq!(<SUT as Unique>::total_for_account(&1), 1);
+        let commodities_for_account = SUT::commodities_for_account(&1);
         assert_eq!(commodities_for_account.len(), 1);
         assert_eq!(
             commodities_for_account[0].id,
@@ -33,7 +38,7 @@ fn mint() {
         assert_eq!(commodities_for_account[0].commodity, Vec::<u8>::default());
         assert_eq!(
             SUT::account_for_commodity::<H256>(Vec::<u8>::default().blake2_256().into()),
-            1
+            Some(1)
         );
     });
 }
@@ -101,10 +106,10 @@ fn burn() {
         assert_eq!(SUT::total(), 0);
         assert_eq!(SUT::burned(), 1);
         assert_eq!(SUT::total_for_account(1), 0);
-        assert_eq!(SUT::commodities_for_account::<u64>(1), vec![]);
+        assert_eq!(SUT::commodities_for_account(&1), vec![]);
         assert_eq!(
             SUT::account_for_commodity::<H256>(Vec::<u8>::default().blake2_256().into()),
-            0
+            None
         );
     });
 }
@@ -145,8 +150,8 @@ fn transfer() {
         assert_eq!(SUT::burned(), 0);
         assert_eq!(SUT::total_for_account(1), 0);
         assert_eq!(SUT::total_for_account(2), 1);
-        assert_eq!(SUT::commodities_for_account::<u64>(1), vec![]);
-        let commodities_for_account = SUT::commodities_for_account::<u64>(2);
+        assert_eq!(SUT::commodities_for_account(&1), vec![]);
+        let commodities_for_account = SUT::commodities_for_account(&2);
         assert_eq!(commodities_for_account.len(), 1);
         assert_eq!(
             commodities_for_account[0].id,
@@ -155,7 +160,7 @@ fn transfer() {
         assert_eq!(commodities_for_account[0].commodity, Vec::<u8>::default());
         assert_eq!(
             SUT::account_for_commodity::<H256>(Vec::<u8>::default().blake2_256().into()),
-            2
+            Some(2)
         );
     });
 }
@@ -171,7 +176,7 @@ fn transfer_err_not_owner() {
                 2,
                 Vec::<u8>::default().blake2_256().into()
             ),
-            Error::<Test, DefaultInstance>::NotCommodityOwner
+            Error::<Test, DefaultInstance>::NotApproved
         );
     });
 }
@@ -185,7 +190,7 @@ fn transfer_err_not_exist() {
                 2,
                 Vec::<u8>::default().blake2_256().into()
             ),
-            Error::<Test, DefaultInstance>::NotCommodityOwner
+            Error::<Test, DefaultInstance>::NonexistentCommodity
         );
     });
 }
@@ -198,7 +203,7 @@ fn transfer_err_max_user() {
         assert_ok!(SUT::mint(Origin::root(), 2, Vec::<u8>::default()));
         assert_eq!(
             SUT::account_for_commodity::<H256>(Vec::<u8>::default().blake2_256().into()),
-            2
+            Some(2)
         );
 
         assert_err!(
@@ -211,3 +216,267 @@ fn transfer_err_max_user() {
         );
     });
 }
+
+#[test]
+fn events_are_emitted() {
+    new_test_ext().execute_with(|| {
+        // Events are not recorded in the genesis block
+        System::set_block_number(1);
+        let id: H256 = Vec::<u8>::default().blake2_256().into();
+
+        assert_ok!(<SUT as Mintable>::mint(&1, Vec::<u8>::default()));
+        assert_ok!(<SUT as Unique>::transfer(&2, &id));
+        assert_ok!(<SUT as Burnable>::burn(&id));
+
+        let events: Vec<TestEvent> = System::events().into_iter().map(|record| record.event).collect();
+        assert_eq!(events, vec![
+            TestEvent::nft(RawEvent::Minted(id, 1)),
+            TestEvent::nft(RawEvent::Transferred(id, 1, 2)),
+            TestEvent::nft(RawEvent::Burned(id, 2)),
+        ]);
+    });
+}
+
+#[test]
+fn default_account_owns_like_any_other() {
+    new_test_ext().execute_with(|| {
+        let id: H256 = Vec::<u8>::default().blake2_256().into();
+        assert_eq!(SUT::owner_of(&id), None);
+
+        assert_ok!(<SUT as Mintable>::mint(&0, Vec::<u8>::default()));
+        assert_eq!(SUT::owner_of(&id), Some(0));
+        assert_eq!(SUT::total_for_account(0), 1);
+        assert_eq!(SUT::commodities_for_account(&0).len(), 1);
+
+        assert_ok!(<SUT as Unique>::transfer(&1, &id));
+        assert_eq!(SUT::owner_of(&id), Some(1));
+        assert_eq!(SUT::total_for_account(0), 0);
+
+        assert_ok!(<SUT as Unique>::transfer(&0, &id));
+        assert_ok!(<SUT as Burnable>::burn(&id));
+        assert_eq!(SUT::owner_of(&id), None);
+        assert_eq!(SUT::total_for_account(0), 0);
+        assert_eq!(SUT::commodities_for_account(&0), vec![]);
+    });
+}
+
+#[test]
+fn nonexistent_commodity_has_no_owner() {
+    new_test_ext().execute_with(|| {
+        let id: H256 = Vec::<u8>::default().blake2_256().into();
+
+        assert_eq!(SUT::owner_of(&id), None);
+        // The trait reports the default account, so existence has to be checked first
+        assert_eq!(<SUT as Unique>::owner_of(&id), 0);
+        assert_err!(
+            <SUT as Unique>::transfer(&1, &id),
+            Error::<Test, DefaultInstance>::NonexistentCommodity
+        );
+        assert_err!(
+            <SUT as Burnable>::burn(&id),
+            Error::<Test, DefaultInstance>::NonexistentCommodity
+        );
+    });
+}
+
+#[test]
+fn commodities_for_account_paginates_in_order_of_id() {
+    new_test_ext().execute_with(|| {
+        for info in 0..3u8 {
+            assert_ok!(<SUT as Mintable>::mint(&1, vec![info]));
+        }
+        assert_ok!(<SUT as Mintable>::mint(&2, vec![3]));
+        let all = SUT::commodities_for_account(&1);
+        assert_eq!(all.len(), 3);
+
+        let first = SUT::commodities_for_account_page(&1, None, 2);
+        assert_eq!(first, all[..2].to_vec());
+        let rest = SUT::commodities_for_account_page(&1, Some(first[1].id), 2);
+        assert_eq!(rest, all[2..].to_vec());
+        assert_eq!(SUT::commodities_for_account_page(&1, Some(rest[0].id), 2), vec![]);
+        assert_eq!(SUT::commodities_for_account_page(&3, None, 2), vec![]);
+    });
+}
+
+#[test]
+fn approved_account_can_transfer_once() {
+    new_test_ext().execute_with(|| {
+        let id: H256 = Vec::<u8>::default().blake2_256().into();
+        assert_ok!(<SUT as Mintable>::mint(&1, Vec::<u8>::default()));
+
+        assert_err!(
+            SUT::transfer_from(Origin::signed(2), 3, id),
+            Error::<Test, DefaultInstance>::NotApproved
+        );
+
+        assert_ok!(SUT::approve(Origin::signed(1), Some(2), id));
+        assert_eq!(SUT::approved(&id), Some(2));
+        assert!(SUT::is_approved_or_owner(&2, &id));
+
+        assert_ok!(SUT::transfer_from(Origin::signed(2), 3, id));
+        assert_eq!(SUT::owner_of(&id), Some(3));
+        // Transferring clears the approval
+        assert_eq!(SUT::approved(&id), None);
+        assert_err!(
+            SUT::transfer_from(Origin::signed(2), 1, id),
+            Error::<Test, DefaultInstance>::NotApproved
+        );
+    });
+}
+
+#[test]
+fn approved_account_and_operator_can_transfer() {
+    new_test_ext().execute_with(|| {
+        let id: H256 = Vec::<u8>::default().blake2_256().into();
+        assert_ok!(<SUT as Mintable>::mint(&1, Vec::<u8>::default()));
+
+        assert_ok!(SUT::approve(Origin::signed(1), Some(2), id));
+        assert_ok!(SUT::transfer(Origin::signed(2), 3, id));
+        assert_eq!(SUT::owner_of(&id), Some(3));
+
+        assert_ok!(SUT::set_approval_for_all(Origin::signed(3), 2, true));
+        assert_ok!(SUT::transfer(Origin::signed(2), 1, id));
+        assert_eq!(SUT::owner_of(&id), Some(1));
+        assert_err!(
+            SUT::transfer(Origin::signed(2), 3, id),
+            Error::<Test, DefaultInstance>::NotApproved
+        );
+    });
+}
+
+#[test]
+fn approve_err_not_owner() {
+    new_test_ext().execute_with(|| {
+        let id: H256 = Vec::<u8>::default().blake2_256().into();
+        assert_ok!(<SUT as Mintable>::mint(&1, Vec::<u8>::default()));
+
+        assert_err!(
+            SUT::approve(Origin::signed(2), Some(2), id),
+            Error::<Test, DefaultInstance>::NotApproved
+        );
+        assert_err!(
+            SUT::approve(Origin::signed(1), Some(2), H256::repeat_byte(1)),
+            Error::<Test, DefaultInstance>::NonexistentCommodity
+        );
+    });
+}
+
+#[test]
+fn operator_can_approve_and_transfer() {
+    new_test_ext().execute_with(|| {
+        let id: H256 = Vec::<u8>::default().blake2_256().into();
+        assert_ok!(<SUT as Mintable>::mint(&1, Vec::<u8>::default()));
+
+        assert_ok!(SUT::set_approval_for_all(Origin::signed(1), 2, true));
+        assert!(SUT::is_approved_for_all(1, 2));
+        assert_ok!(SUT::approve(Origin::signed(2), Some(3), id));
+        assert_eq!(SUT::approved(&id), Some(3));
+        assert_ok!(SUT::transfer_from(Origin::signed(2), 2, id));
+        assert_eq!(SUT::owner_of(&id), Some(2));
+
+        // Operators are approved per owner
+        assert_ok!(<SUT as Mintable>::mint(&1, vec![0]));
+        assert_ok!(SUT::set_approval_for_all(Origin::signed(1), 2, false));
+        assert!(!SUT::is_approved_for_all(1, 2));
+        assert_err!(
+            SUT::transfer_from(Origin::signed(2), 2, vec![0].blake2_256().into()),
+            Error::<Test, DefaultInstance>::NotApproved
+        );
+    });
+}
+
+#[test]
+fn approval_events_are_emitted() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let id: H256 = Vec::<u8>::default().blake2_256().into();
+        assert_ok!(<SUT as Mintable>::mint(&1, Vec::<u8>::default()));
+
+        assert_ok!(SUT::approve(Origin::signed(1), Some(2), id));
+        assert_ok!(SUT::approve(Origin::signed(1), None, id));
+        assert_ok!(SUT::set_approval_for_all(Origin::signed(1), 3, true));
+
+        let events: Vec<TestEvent> = System::events().into_iter().map(|record| record.event).collect();
+        assert_eq!(events[1..], [
+            TestEvent::nft(RawEvent::Approval(id, 1, Some(2))),
+            TestEvent::nft(RawEvent::Approval(id, 1, None)),
+            TestEvent::nft(RawEvent::ApprovalForAll(1, 3, true)),
+        ]);
+    });
+}
+
+#[test]
+fn mint_increments_nonce() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(SUT::mint_nonce(), 0);
+        assert_ok!(<SUT as Mintable>::mint(&1, vec![0]));
+        assert_ok!(<SUT as Burnable>::burn(&vec![0].blake2_256().into()));
+        assert_ok!(<SUT as Mintable>::mint(&1, vec![0]));
+        assert_eq!(SUT::mint_nonce(), 2);
+    });
+}
+
+#[test]
+fn id_strategies() {
+    let info: Vec<u8> = vec![1];
+
+    assert_eq!(
+        <ContentHash as CommodityIdStrategy<Test, _>>::commodity_id(&info, 7),
+        info.blake2_256().into()
+    );
+
+    let first = <ContentHashWithNonce as CommodityIdStrategy<Test, _>>::commodity_id(&info, 0);
+    let second = <ContentHashWithNonce as CommodityIdStrategy<Test, _>>::commodity_id(&info, 1);
+    assert_ne!(first, second);
+    assert_eq!(first, (info.clone(), 0u64).blake2_256().into());
+
+    assert_eq!(
+        <Sequential as CommodityIdStrategy<Test, _>>::commodity_id(&info, 258),
+        H256::from_low_u64_be(258)
+    );
+    assert!(
+        <Sequential as CommodityIdStrategy<Test, _>>::commodity_id(&info, 1)
+            < <Sequential as CommodityIdStrategy<Test, _>>::commodity_id(&info, 2)
+    );
+}
+
+#[test]
+fn migrate_commodities_to_double_map() {
+    new_test_ext().execute_with(|| {
+        let commodity = Commodity {
+            id: Vec::<u8>::default().blake2_256().into(),
+            commodity: vec![7],
+        };
+        // The layout before the double map: a sorted list of commodities per account
+        put_storage_value(
+            b"Commodity",
+            b"CommoditiesForAccount",
+            &1u64.blake2_128_concat(),
+            vec![commodity.clone()],
+        );
+        AccountForCommodity::<Test>::insert(commodity.id, 1);
+
+        SUT::on_runtime_upgrade();
+
+        let commodities_for_account = SUT::commodities_for_account(&1);
+        assert_eq!(commodities_for_account, vec![commodity]);
+        assert_eq!(commodities_for_account[0].commodity, vec![7]);
+        assert_eq!(
+            StorageIterator::<Vec<CommodityFor<Test, DefaultInstance>>>::new(b"Commodity", b"CommoditiesForAccount").count(),
+            0
+        );
+        assert_eq!(StorageVersion::<DefaultInstance>::get(), Releases::V2_0_0);
+    });
+}
+
+#[test]
+fn genesis_starts_at_latest_storage_version() {
+    let storage = GenesisConfig::default()
+        .build_storage::<Test, DefaultInstance>()
+        .unwrap();
+    sp_io::TestExternalities::from(storage).execute_with(|| {
+        assert_eq!(StorageVersion::<DefaultInstance>::get(), Releases::V2_0_0);
+        // Nothing is migrated when the chain already starts at the latest layout
+        assert_eq!(SUT::on_runtime_upgrade(), <Test as frame_system::Trait>::DbWeight::get().reads(1));
+    });
+}
@@ -0,0 +1,46 @@
+//! Runtime API definition for querying the NFTs and registries of the
+//! VA registry pallet, so that clients don't have to decode raw storage.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Queries of the NFTs held by the NFT pallet and the registries that minted them.
+    pub trait VaRegistryApi<AccountId, AssetId, AssetInfo, Attestation, RegistryId, RegistryInfo> where
+        AccountId: Codec,
+        AssetId: Codec,
+        AssetInfo: Codec,
+        Attestation: Codec,
+        RegistryId: Codec,
+        RegistryInfo: Codec,
+    {
+        /// The owner of an asset, if it exists.
+        fn owner_of(asset_id: AssetId) -> Option<AccountId>;
+        /// The info of an asset, which includes its registry id, if it exists.
+        fn asset_info(asset_id: AssetId) -> Option<AssetInfo>;
+        /// The anchor an asset was last proven against and the block its validity ends at,
+        /// for assets of registries verified against anchors.
+        fn attestation(asset_id: AssetId) -> Option<Attestation>;
+        /// The URI of an asset's metadata: its own token URI when set, otherwise the
+        /// base URI of its registry followed by the hex of the asset id.
+        fn asset_uri(asset_id: AssetId) -> Option<Vec<u8>>;
+        /// Whether an asset is held in a registry and has not been revoked and, for
+        /// registries verified against anchors, the anchor it was last proven against
+        /// still exists, has not been revoked and has not expired.
+        fn is_live(asset_id: AssetId) -> bool;
+        /// Up to `limit` assets owned by an account along with their info, in order
+        /// of their id, starting after the asset id `start_after` when given.
+        fn assets_for_account(account: AccountId, start_after: Option<AssetId>, limit: u32) -> Vec<(AssetId, AssetInfo)>;
+        /// The info of a registry, if it exists.
+        fn registry_info(registry_id: RegistryId) -> Option<RegistryInfo>;
+        /// Up to `limit` asset ids held in a registry, in order of their id,
+        /// starting after the asset id `start_after` when given.
+        fn assets_in_registry(registry_id: RegistryId, start_after: Option<AssetId>, limit: u32) -> Vec<AssetId>;
+        /// The total number of assets that exist (minted - burned).
+        fn total() -> u128;
+        /// The total number of assets that have been burned.
+        fn burned() -> u128;
+    }
+}
@@ -0,0 +1,218 @@
+//! RPC interface for querying the NFTs and registries of the VA registry
+//! pallet. Methods for assets are in the `nft_` namespace and methods for
+//! registries in the `registry_` namespace. Every method takes an optional
+//! block hash to query at, defaulting to the best block.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_va_registry_rpc_runtime_api::VaRegistryApi as VaRegistryRuntimeApi;
+
+/// The maximum number of assets returned in a single page.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// A page of results, with a cursor to request the following page.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<Item, Cursor = Item> {
+    /// The items of this page.
+    pub items: Vec<Item>,
+    /// Cursor to pass as `start_after` for the next page, if there may be more items.
+    pub next: Option<Cursor>,
+}
+
+#[rpc]
+pub trait VaRegistryApi<BlockHash, AccountId, AssetId, AssetInfo, Attestation, RegistryId, RegistryInfo> {
+    /// The owner of an asset, if it exists.
+    #[rpc(name = "nft_ownerOf")]
+    fn owner_of(&self, asset_id: AssetId, at: Option<BlockHash>) -> Result<Option<AccountId>>;
+
+    /// The info of an asset, which includes its registry id, if it exists.
+    #[rpc(name = "nft_assetInfo")]
+    fn asset_info(&self, asset_id: AssetId, at: Option<BlockHash>) -> Result<Option<AssetInfo>>;
+
+    /// The anchor an asset was last proven against and the block its validity ends at, for
+    /// assets of registries verified against anchors. Unlike the anchor in the asset info,
+    /// this follows reattestations.
+    #[rpc(name = "nft_attestation")]
+    fn attestation(&self, asset_id: AssetId, at: Option<BlockHash>) -> Result<Option<Attestation>>;
+
+    /// The URI of an asset's metadata, if it has one.
+    #[rpc(name = "nft_tokenUri")]
+    fn asset_uri(&self, asset_id: AssetId, at: Option<BlockHash>) -> Result<Option<String>>;
+
+    /// Whether an asset is held in a registry and has not been revoked and, for registries
+    /// verified against anchors, the anchor it was last proven against still exists, has
+    /// not been revoked and has not expired.
+    #[rpc(name = "nft_isLive")]
+    fn is_live(&self, asset_id: AssetId, at: Option<BlockHash>) -> Result<bool>;
+
+    /// A page of the assets owned by an account along with their info, in order of their id.
+    /// The page starts after the `start_after` cursor when given and holds up to `limit`
+    /// assets, which defaults to and is capped at [MAX_PAGE_SIZE].
+    #[rpc(name = "nft_assetsForAccount")]
+    fn assets_for_account(&self,
+                          account: AccountId,
+                          start_after: Option<AssetId>,
+                          limit: Option<u32>,
+                          at: Option<BlockHash>,
+    ) -> Result<Page<(AssetId, AssetInfo), AssetId>>;
+
+    /// The total number of assets that exist (minted - burned).
+    #[rpc(name = "nft_total")]
+    fn total(&self, at: Option<BlockHash>) -> Result<NumberOrHex>;
+
+    /// The total number of assets that have been burned.
+    #[rpc(name = "nft_burned")]
+    fn burned(&self, at: Option<BlockHash>) -> Result<NumberOrHex>;
+
+    /// The info of a registry, if it exists.
+    #[rpc(name = "registry_info")]
+    fn registry_info(&self, registry_id: RegistryId, at: Option<BlockHash>) -> Result<Option<RegistryInfo>>;
+
+    /// A page of the asset ids held in a registry, in order of their id. The page starts
+    /// after the `start_after` cursor when given and holds up to `limit` ids, which
+    /// defaults to and is capped at [MAX_PAGE_SIZE].
+    #[rpc(name = "registry_assets")]
+    fn assets_in_registry(&self,
+                          registry_id: RegistryId,
+                          start_after: Option<AssetId>,
+                          limit: Option<u32>,
+                          at: Option<BlockHash>,
+    ) -> Result<Page<AssetId>>;
+}
+
+/// An implementation of the VA registry RPC methods, querying the runtime API of the client.
+pub struct VaRegistry<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> VaRegistry<C, B> {
+    /// Create a new `VaRegistry` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+/// Error code for errors from the runtime.
+const RUNTIME_ERROR: i64 = 1;
+
+fn runtime_error<E: std::fmt::Debug>(err: E) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(RUNTIME_ERROR),
+        message: "Unable to query the VA registry.".into(),
+        data: Some(format!("{:?}", err).into()),
+    }
+}
+
+impl<C, Block, AccountId, AssetId, AssetInfo, Attestation, RegistryId, RegistryInfo>
+    VaRegistryApi<<Block as BlockT>::Hash, AccountId, AssetId, AssetInfo, Attestation, RegistryId, RegistryInfo>
+    for VaRegistry<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block>,
+    C::Api: VaRegistryRuntimeApi<Block, AccountId, AssetId, AssetInfo, Attestation, RegistryId, RegistryInfo>,
+    AccountId: Codec,
+    AssetId: Codec + Clone,
+    AssetInfo: Codec,
+    Attestation: Codec,
+    RegistryId: Codec,
+    RegistryInfo: Codec,
+{
+    fn owner_of(&self, asset_id: AssetId, at: Option<<Block as BlockT>::Hash>) -> Result<Option<AccountId>> {
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        self.client.runtime_api().owner_of(&at, asset_id).map_err(runtime_error)
+    }
+
+    fn asset_info(&self, asset_id: AssetId, at: Option<<Block as BlockT>::Hash>) -> Result<Option<AssetInfo>> {
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        self.client.runtime_api().asset_info(&at, asset_id).map_err(runtime_error)
+    }
+
+    fn attestation(&self, asset_id: AssetId, at: Option<<Block as BlockT>::Hash>) -> Result<Option<Attestation>> {
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        self.client.runtime_api().attestation(&at, asset_id).map_err(runtime_error)
+    }
+
+    fn asset_uri(&self, asset_id: AssetId, at: Option<<Block as BlockT>::Hash>) -> Result<Option<String>> {
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let uri = self.client.runtime_api().asset_uri(&at, asset_id).map_err(runtime_error)?;
+        Ok(uri.map(|uri| String::from_utf8_lossy(&uri).into_owned()))
+    }
+
+    fn is_live(&self, asset_id: AssetId, at: Option<<Block as BlockT>::Hash>) -> Result<bool> {
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        self.client.runtime_api().is_live(&at, asset_id).map_err(runtime_error)
+    }
+
+    fn assets_for_account(&self,
+                          account: AccountId,
+                          start_after: Option<AssetId>,
+                          limit: Option<u32>,
+                          at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Page<(AssetId, AssetInfo), AssetId>> {
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let limit = limit.unwrap_or(MAX_PAGE_SIZE).min(MAX_PAGE_SIZE);
+
+        let items = self.client.runtime_api()
+            .assets_for_account(&at, account, start_after, limit)
+            .map_err(runtime_error)?;
+
+        // A full page may be followed by more items
+        let next = if items.len() == limit as usize {
+            items.last().map(|(asset_id, _)| asset_id.clone())
+        } else {
+            None
+        };
+
+        Ok(Page { items, next })
+    }
+
+    fn total(&self, at: Option<<Block as BlockT>::Hash>) -> Result<NumberOrHex> {
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        self.client.runtime_api().total(&at).map(Into::into).map_err(runtime_error)
+    }
+
+    fn burned(&self, at: Option<<Block as BlockT>::Hash>) -> Result<NumberOrHex> {
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        self.client.runtime_api().burned(&at).map(Into::into).map_err(runtime_error)
+    }
+
+    fn registry_info(&self,
+                     registry_id: RegistryId,
+                     at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<RegistryInfo>> {
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        self.client.runtime_api().registry_info(&at, registry_id).map_err(runtime_error)
+    }
+
+    fn assets_in_registry(&self,
+                          registry_id: RegistryId,
+                          start_after: Option<AssetId>,
+                          limit: Option<u32>,
+                          at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Page<AssetId>> {
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let limit = limit.unwrap_or(MAX_PAGE_SIZE).min(MAX_PAGE_SIZE);
+
+        let items = self.client.runtime_api()
+            .assets_in_registry(&at, registry_id, start_after, limit)
+            .map_err(runtime_error)?;
+
+        // A full page may be followed by more items
+        let next = if items.len() == limit as usize { items.last().cloned() } else { None };
+
+        Ok(Page { items, next })
+    }
+}
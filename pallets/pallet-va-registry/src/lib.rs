@@ -11,26 +11,32 @@
 //! struct. Values for the fields are provided upon each call to
 //! [mint](struct.Module.html#method.mint) a new NFT. As can be seen in
 //! the values field of the [MintInfo] struct. MintInfo also takes a
-//! list of proofs and an anchor id. The mint method will hash the
-//! values into leaves of a merkle tree and aggregate with the proofs
-//! to generate the root. When the root hash matches that of the anchor,
-//! a mint can be verified.
+//! list of proofs, the static proofs of the document and an anchor id.
+//! The mint method will hash the values into leaves of a merkle tree
+//! and hand them to the [MintVerifier] selected by the registry in the
+//! verifier attribute of its [RegistryInfo]. The default verifier
+//! aggregates the leaves with the proofs to generate the root. When the
+//! root hash matches that of the anchor, a mint can be verified. Other
+//! built-in verifiers check a signature of an attester over the leaves,
+//! or verify every mint for testing.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
     decl_module, decl_storage, decl_event, decl_error,
-    ensure, dispatch};
+    ensure, dispatch, traits::Get,
+    storage::{StorageDoubleMap, generator::StorageDoubleMap as StorageDoubleMapGenerator}};
+use codec::Decode;
 use frame_system::ensure_signed;
 use sp_std::{vec::Vec, cmp::Eq};
-use pallet_nft::InRegistry;
-use unique_assets::traits::{Unique, Nft, Mintable};
+use pallet_anchor::{AnchorData, AnchorProvider};
+use unique_assets::traits::{Nft, Mintable, Burnable};
 pub use types::{*, VerifierRegistry};
+pub use verifiers::*;
 use sp_runtime::traits::Hash;
+use sp_core::H256;
 
 // TODO:
-//- Fix unit tests for pallet_nft
-//- Write tests for transfer and burn dispatchables in va-registry
 //- Integrate bridge pallet
 //- Review spec, compare with implementation
 //- Figure abstractions for nft macro
@@ -42,6 +48,9 @@ mod types;
 // TODO: tmp until integrated w/ cent chain
 mod proofs;
 
+// Verifier backends for registries
+mod verifiers;
+
 #[cfg(test)]
 mod mock;
 
@@ -49,18 +58,57 @@ mod mock;
 mod tests;
 
 
-pub trait Trait: frame_system::Trait + pallet_nft::Trait {
+pub trait Trait: frame_system::Trait<Hash = H256> + pallet_nft::Trait<CommodityInfo = AssetInfo<H256>> {
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+    /// Source of the anchored document roots that mints are verified against.
+    type Anchors: AnchorProvider<Self::Hash, Self::AccountId, Self::BlockNumber>;
+    /// The maximum length of the name of a registry.
+    type MaxNameLength: Get<u32>;
+    /// The maximum length of the symbol of a registry.
+    type MaxSymbolLength: Get<u32>;
+    /// The maximum length of the base URI of a registry and of a token URI.
+    type MaxUriLength: Get<u32>;
 }
 
 decl_storage! {
     trait Store for Module<T: Trait> as VARegistry {
-        /// This is a dummy store for testing verification in template node.
-        Anchor get(fn get_anchor_by_id): map hasher(identity) T::Hash => Option<T::Hash>;
         /// Nonce for generating new registry ids.
         RegistryNonce: RegistryId;
         /// A mapping of all created registries and their metadata.
         Registries: map hasher(blake2_128_concat) RegistryId => RegistryInfo;
+        /// The admin of each registry, initially the account that created it.
+        RegistryAdmin get(fn registry_admin): map hasher(blake2_128_concat) RegistryId => Option<T::AccountId>;
+        /// The number of tokens currently held in each registry (minted - burned).
+        RegistryTotal get(fn registry_total): map hasher(blake2_128_concat) RegistryId => u128;
+        /// The number of tokens of each registry owned by an account.
+        RegistryAccountTotal get(fn registry_account_total):
+            double_map hasher(blake2_128_concat) RegistryId, hasher(blake2_128_concat) T::AccountId => u64;
+        /// Accounts allowed to mint in registries with the allowlist mint policy.
+        Minters get(fn is_minter):
+            double_map hasher(blake2_128_concat) RegistryId, hasher(blake2_128_concat) T::AccountId => bool;
+        /// Destinations approved by the admin for the next transfer of tokens in registries
+        /// with the admin approved transfer policy.
+        TransferApprovals get(fn transfer_approval): map hasher(identity) AssetId<T> => Option<T::AccountId>;
+        /// Accounts allowed to receive tokens of registries with the allowlist transfer policy.
+        Recipients get(fn is_recipient):
+            double_map hasher(blake2_128_concat) RegistryId, hasher(blake2_128_concat) T::AccountId => bool;
+        /// Tokens of revocable registries that have been revoked.
+        Revoked get(fn is_revoked): map hasher(identity) AssetId<T> => bool;
+        /// The anchor each token of a registry verified against anchors was last proven
+        /// against, and the block until which the token is valid.
+        Attestations get(fn attestation): map hasher(identity) AssetId<T> => Option<Attestation<T::Hash, T::BlockNumber>>;
+        /// The proofs consumed by mints of each registry, so they can't be replayed into the
+        /// registry. Mints verified against anchors are keyed by the hash of the anchored
+        /// document root and leaves, so not even under another anchor, and mints verified by
+        /// an attester by the hash of the signed message.
+        ConsumedProofs get(fn is_proof_consumed):
+            double_map hasher(blake2_128_concat) RegistryId, hasher(identity) T::Hash => bool;
+        /// Registries for which minting has been frozen by the admin.
+        MintingFrozen get(fn minting_frozen): map hasher(blake2_128_concat) RegistryId => bool;
+        /// Registries for which the metadata has been frozen by the admin.
+        MetadataFrozen get(fn metadata_frozen): map hasher(blake2_128_concat) RegistryId => bool;
+        /// URIs of tokens set by the admin of their registry, overriding the base URI of the registry.
+        TokenUris get(fn token_uri): map hasher(identity) AssetId<T> => Option<bytes>;
         /// A list of asset ids for each registry.
         // TODO: Try a map of BTreeSets as well, and do a benchmark comparison
         NftLists: double_map hasher(identity) RegistryId, hasher(identity) AssetId<T> => ();
@@ -72,6 +120,8 @@ decl_event!(
     where
         CommodityId = AssetId<T>,
         AccountId   = <T as frame_system::Trait>::AccountId,
+        Hash        = <T as frame_system::Trait>::Hash,
+        BlockNumber = <T as frame_system::Trait>::BlockNumber,
     {
         /// Successful mint of an NFT from fn [`mint`](struct.Module.html#method.mint)
         Mint(CommodityId),
@@ -80,20 +130,116 @@ decl_event!(
         RegistryCreated(RegistryId),
         /// Ownership of the commodity has been transferred to the account.
         Transferred(CommodityId, AccountId),
+        /// Successful burn of an NFT from fn [`burn`](struct.Module.html#method.burn)
+        Burned(CommodityId),
+        /// Admin rights of the registry have been transferred to the account.
+        RegistryAdminChanged(RegistryId, AccountId),
+        /// The registry info of an empty registry has been replaced.
+        RegistryUpdated(RegistryId),
+        /// No more tokens can be minted in the registry.
+        MintingFrozen(RegistryId),
+        /// An empty registry has been removed.
+        RegistryDestroyed(RegistryId),
+        /// The name, symbol and base URI of the registry have been replaced.
+        MetadataUpdated(RegistryId),
+        /// The URI of the token has been set, or cleared.
+        TokenUriSet(CommodityId),
+        /// The metadata of the registry and its tokens can no longer be changed.
+        MetadataFrozen(RegistryId),
+        /// The account has been added to the allowlist of minters of the registry.
+        MinterAdded(RegistryId, AccountId),
+        /// The account has been removed from the allowlist of minters of the registry.
+        MinterRemoved(RegistryId, AccountId),
+        /// The admin has approved the next transfer of the token to the account, or
+        /// revoked the approval when `None`.
+        TransferApproved(CommodityId, Option<AccountId>),
+        /// The account has been added to the allowlist of recipients of the registry.
+        RecipientAdded(RegistryId, AccountId),
+        /// The account has been removed from the allowlist of recipients of the registry.
+        RecipientRemoved(RegistryId, AccountId),
+        /// The token has been revoked, and burned when the flag is set. [commodity_id, burned]
+        Revoked(CommodityId, bool),
+        /// The values of the token have been proven against a newer anchor, extending its
+        /// validity until the block. [commodity_id, anchor_id, valid_until]
+        Reattested(CommodityId, Hash, BlockNumber),
     }
 );
 
 decl_error! {
     pub enum Error for Module<T: Trait> {
-        /// TODO: Tmp until using anchor pallet
+        /// The anchor id of a mint doesn't refer to an anchored document.
         DocumentNotAnchored,
         /// A specified registry is not in the module storage Registries map.
         RegistryDoesNotExist,
         /// The values vector provided to a mint call doesn't match the length of the specified
         /// registry's fields vector.
         InvalidMintingValues,
+        /// The salts vector provided to a mint call doesn't match the length of its values vector.
+        InvalidMintingSalts,
+        /// The proofs provided to a mint call do not prove the field/value leaves against the
+        /// anchored document root.
+        InvalidProofs,
+        /// The mint is not signed by the attester of the registry.
+        InvalidAttestation,
         // Thrown when someone who is not the owner of a commodity attempts to transfer or burn it.
         NotCommodityOwner,
+        /// The commodity is not held in the specified registry.
+        NotInRegistry,
+        /// The registry does not allow owners to burn their own tokens.
+        OwnerCannotBurn,
+        /// Thrown when someone who is not the admin of a registry attempts to manage it.
+        NotRegistryAdmin,
+        /// The registry still holds tokens and can't be updated or destroyed.
+        RegistryNotEmpty,
+        /// Minting has been frozen for the registry.
+        RegistryFrozen,
+        /// The name of a registry is longer than `MaxNameLength`.
+        NameTooLong,
+        /// The symbol of a registry is longer than `MaxSymbolLength`.
+        SymbolTooLong,
+        /// A base URI or token URI is longer than `MaxUriLength`.
+        UriTooLong,
+        /// The metadata of the registry has been frozen.
+        MetadataFrozen,
+        /// The registry already holds its maximum supply of tokens.
+        RegistrySupplyExceeded,
+        /// The account already owns the maximum number of tokens of the registry.
+        TooManyTokensForAccount,
+        /// The registry only allows its admin to mint.
+        MintRequiresAdmin,
+        /// The registry only allows the accounts on its allowlist of minters to mint.
+        NotAllowedMinter,
+        /// The registry only allows the owner of the anchor a mint is proven against to mint.
+        NotAnchorOwner,
+        /// The registry only allows minting to the account of the minter.
+        CanOnlyMintToSelf,
+        /// The mint policy of the registry requires the registry to verify mints against anchors.
+        MintPolicyRequiresAnchors,
+        /// The tokens of the registry can't be transferred.
+        NonTransferable,
+        /// The registry admin has not approved the transfer of the token to the destination.
+        TransferNotApproved,
+        /// The destination is not on the allowlist of recipients of the registry.
+        RecipientNotAllowed,
+        /// The registry does not allow its tokens to be revoked.
+        NotRevocable,
+        /// Thrown when someone who is not the registry admin or the anchor owner of a token
+        /// attempts to revoke it.
+        NotRevoker,
+        /// The token has already been revoked.
+        AlreadyRevoked,
+        /// The token has been revoked and can't be transferred.
+        TokenRevoked,
+        /// The anchor a mint is proven against has expired.
+        AnchorExpired,
+        /// The anchor a mint is proven against has been revoked by its owner.
+        AnchorRevoked,
+        /// The registry does not verify its tokens against anchors, so they can't be reattested.
+        NotAnchorVerified,
+        /// The anchor of a reattestation must expire after the current attestation of the token.
+        ValidityNotExtended,
+        /// The proven document has already been used to mint a token of the registry.
+        ProofAlreadyUsed,
     }
 }
 
@@ -102,76 +248,623 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 10_000]
-        pub fn tmp_set_anchor(origin, anchor_id: T::Hash, anchor: T::Hash
-        ) -> dispatch::DispatchResult {
-            Ok(<Anchor<T>>::insert(anchor_id, anchor))
-        }
+        const MaxNameLength: u32 = T::MaxNameLength::get();
+        const MaxSymbolLength: u32 = T::MaxSymbolLength::get();
+        const MaxUriLength: u32 = T::MaxUriLength::get();
 
         #[weight = 10_000]
         pub fn create_registry(origin,
                                info: RegistryInfo,
         ) -> dispatch::DispatchResult {
-            ensure_signed(origin)?;
+            let who = ensure_signed(origin)?;
+            Self::ensure_valid_metadata(&info.name, &info.symbol, &info.base_uri)?;
+            Self::ensure_valid_mint_policy(&info)?;
 
             let registry_id = <Self as VerifierRegistry>::create_registry(&info)?;
 
+            // The creator administers the registry
+            <RegistryAdmin<T>>::insert(registry_id, who);
+
             // Emit event
             Self::deposit_event(Event::<T>::RegistryCreated(registry_id));
 
             Ok(())
         }
 
+        /// Mint an NFT in a registry, when the mint info is verified by the
+        /// verifier of the registry.
+        ///
+        /// The minted asset holds the field names of the registry along with the
+        /// verified values and the anchor id of the mint info.
+        ///
+        /// The dispatch origin for this call must be allowed to mint by the mint
+        /// policy of the registry.
+        ///
+        /// - `owner_account`: Receiver of the NFT.
+        /// - `registry_id`: The registry to mint the NFT in.
+        /// - `mint_info`: Values of the fields of the registry, and the evidence
+        ///   the verifier requires for them.
         #[weight = 10_000]
         pub fn mint(origin,
                     owner_account: <T as frame_system::Trait>::AccountId,
-                    commodity_info: T::CommodityInfo,
+                    registry_id: RegistryId,
                     mint_info: MintInfo<<T as frame_system::Trait>::Hash>,
         ) -> dispatch::DispatchResult {
-            ensure_signed(origin)?;
+            let who = ensure_signed(origin)?;
+            Self::ensure_may_mint(&who, &owner_account, registry_id, &mint_info)?;
 
             // Internal mint validates proofs and modifies state or returns error
             let commodity_id = <Self as VerifierRegistry>::mint(owner_account,
-                                                                commodity_info,
+                                                                registry_id,
                                                                 mint_info)?;
 
             // Mint event
             Self::deposit_event(RawEvent::Mint(commodity_id));
 
             Ok(())
-       }
+        }
 
         /// Transfer a commodity to a new owner.
         ///
-        /// The dispatch origin for this call must be the commodity owner.
+        /// The dispatch origin for this call must be the commodity owner, the account
+        /// approved for the commodity, or an operator of the owner. Transferring clears
+        /// the approved account.
         ///
         /// This function will throw an error if the new owner already owns the maximum
         /// number of this type of commodity.
         ///
         /// - `dest_account`: Receiver of the commodity.
-        /// - `commodity_id`: The hash (calculated by the runtime system's hashing algorithm)
-        ///   of the info that defines the commodity to destroy.
+        /// - `commodity_id`: The id of the commodity to transfer, as derived by the `IdStrategy`
+        ///   of the NFT pallet.
         #[weight = 10_000]
         pub fn transfer(origin, dest_account: T::AccountId, commodity_id: AssetId<T>) -> dispatch::DispatchResult {
             let who = ensure_signed(origin)?;
-            ensure!(who == <pallet_nft::Module<T>>::account_for_commodity(&commodity_id), Error::<T>::NotCommodityOwner);
 
-            <pallet_nft::Module<T> as Unique>::transfer(&dest_account, &commodity_id)?;
+            <pallet_nft::Module<T>>::do_transfer_from(&who, &dest_account, &commodity_id)?;
             Self::deposit_event(RawEvent::Transferred(commodity_id.clone(), dest_account.clone()));
             Ok(())
         }
+
+        /// Approve an account to transfer a commodity, or clear the approval with `None`.
+        ///
+        /// The dispatch origin for this call must be the commodity owner or an operator
+        /// of the owner.
+        #[weight = 10_000]
+        pub fn approve(origin, approved: Option<T::AccountId>, commodity_id: AssetId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            <pallet_nft::Module<T>>::do_approve(&who, approved, &commodity_id)
+        }
+
+        /// Approve or unapprove an operator to transfer and approve all commodities
+        /// of the sender.
+        #[weight = 10_000]
+        pub fn set_approval_for_all(origin, operator: T::AccountId, approved: bool) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            <pallet_nft::Module<T>>::do_set_approval_for_all(&who, &operator, approved);
+            Ok(())
+        }
+
+        /// Destroy a commodity held in a registry.
+        ///
+        /// The dispatch origin for this call must be the registry admin, or the commodity
+        /// owner when the registry was created with `owner_can_burn` set.
+        ///
+        /// - `commodity_id`: The id of the commodity to destroy, as derived by the `IdStrategy`
+        ///   of the NFT pallet.
+        #[weight = 10_000]
+        pub fn burn(origin, commodity_id: AssetId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            let registry_id = Self::held_in_registry(&commodity_id).ok_or(Error::<T>::NotInRegistry)?;
+
+            // The registry admin can always burn, owners only if the registry allows it
+            if Self::registry_admin(registry_id) != Some(who.clone()) {
+                ensure!(<pallet_nft::Module<T>>::owner_of(&commodity_id) == Some(who.clone()), Error::<T>::NotCommodityOwner);
+                ensure!(Registries::get(registry_id).owner_can_burn, Error::<T>::OwnerCannotBurn);
+            }
+
+            <pallet_nft::Module<T> as Burnable>::burn(&commodity_id)?;
+
+            Self::deposit_event(RawEvent::Burned(commodity_id));
+            Ok(())
+        }
+
+        /// Prove the values of a token against a newer anchor, to extend the validity of
+        /// the token until that anchor expires.
+        ///
+        /// The dispatch origin for this call must be the token owner, and the registry of
+        /// the token must verify mints against anchors.
+        ///
+        /// - `commodity_id`: The token to reattest.
+        /// - `mint_info`: The values of the token, with their salts and proofs against
+        ///   the newer anchor.
+        #[weight = 10_000]
+        pub fn reattest(origin, commodity_id: AssetId<T>, mint_info: MintInfo<<T as frame_system::Trait>::Hash>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            let registry_id = Self::held_in_registry(&commodity_id).ok_or(Error::<T>::NotInRegistry)?;
+            ensure!(<pallet_nft::Module<T>>::owner_of(&commodity_id) == Some(who.clone()), Error::<T>::NotCommodityOwner);
+            ensure!(!Self::is_revoked(&commodity_id), Error::<T>::TokenRevoked);
+            ensure!(Registries::get(registry_id).verifier == VerifierKind::MerkleAnchor, Error::<T>::NotAnchorVerified);
+
+            // The same values must be proven as at mint
+            let asset_info = Self::asset_info(&commodity_id).ok_or(Error::<T>::NotInRegistry)?;
+            ensure!(mint_info.values == asset_info.values, Error::<T>::InvalidMintingValues);
+            let leaves = Self::leaves_of(asset_info.fields, &mint_info)?;
+            MintVerifier::<T>::verify(&MerkleAnchorVerifier, registry_id, &who, &leaves, &mint_info)?;
+
+            let anchor_data = Self::get_live_anchor(mint_info.anchor_id)?;
+            ensure!(
+                Self::attestation(&commodity_id).map_or(true, |current| anchor_data.expiration_block > current.valid_until),
+                Error::<T>::ValidityNotExtended
+            );
+            <Attestations<T>>::insert(&commodity_id, Attestation {
+                anchor_id: anchor_data.id,
+                valid_until: anchor_data.expiration_block,
+            });
+
+            Self::deposit_event(RawEvent::Reattested(commodity_id, anchor_data.id, anchor_data.expiration_block));
+            Ok(())
+        }
+
+        /// Revoke a token of a revocable registry, for instance when the document it was
+        /// proven against has been superseded. A revoked token can't be transferred, and
+        /// is burned instead when `burn` is set.
+        ///
+        /// The dispatch origin for this call must be the registry admin, or, in registries
+        /// that verify mints against anchors, the owner of the anchor the token was last
+        /// proven against.
+        #[weight = 10_000]
+        pub fn revoke(origin, commodity_id: AssetId<T>, burn: bool) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            let registry_id = Self::held_in_registry(&commodity_id).ok_or(Error::<T>::NotInRegistry)?;
+            let registry_info = Registries::get(registry_id);
+            ensure!(registry_info.revocable, Error::<T>::NotRevocable);
+
+            if Self::registry_admin(registry_id) != Some(who.clone()) {
+                // Only an anchor the values were proven against vouches for its owner
+                let anchor_owner = match registry_info.verifier {
+                    VerifierKind::MerkleAnchor => Self::attestation(&commodity_id)
+                        .and_then(|attestation| T::Anchors::get_anchor_by_id(attestation.anchor_id))
+                        .map(|anchor| anchor.owner),
+                    _ => None,
+                };
+                ensure!(anchor_owner == Some(who), Error::<T>::NotRevoker);
+            }
+
+            if burn {
+                <pallet_nft::Module<T> as Burnable>::burn(&commodity_id)?;
+            } else {
+                ensure!(!Self::is_revoked(&commodity_id), Error::<T>::AlreadyRevoked);
+                <Revoked<T>>::insert(&commodity_id, true);
+            }
+
+            Self::deposit_event(RawEvent::Revoked(commodity_id, burn));
+            Ok(())
+        }
+
+        /// Hand the admin rights of a registry to another account.
+        ///
+        /// The dispatch origin for this call must be the registry admin.
+        #[weight = 10_000]
+        pub fn transfer_registry_admin(origin, registry_id: RegistryId, new_admin: T::AccountId) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_registry_admin(&who, registry_id)?;
+
+            <RegistryAdmin<T>>::insert(registry_id, &new_admin);
+
+            Self::deposit_event(RawEvent::RegistryAdminChanged(registry_id, new_admin));
+            Ok(())
+        }
+
+        /// Replace the info (fields, `owner_can_burn`, verifier and metadata) of a registry.
+        ///
+        /// The dispatch origin for this call must be the registry admin, the
+        /// registry must not hold any tokens and its metadata must not be frozen.
+        #[weight = 10_000]
+        pub fn update_registry(origin, registry_id: RegistryId, info: RegistryInfo) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_registry_admin(&who, registry_id)?;
+            ensure!(Self::registry_total(registry_id) == 0, Error::<T>::RegistryNotEmpty);
+            ensure!(!Self::metadata_frozen(registry_id), Error::<T>::MetadataFrozen);
+            Self::ensure_valid_metadata(&info.name, &info.symbol, &info.base_uri)?;
+            Self::ensure_valid_mint_policy(&info)?;
+
+            Registries::insert(registry_id, info);
+
+            Self::deposit_event(RawEvent::RegistryUpdated(registry_id));
+            Ok(())
+        }
+
+        /// Permanently disable minting in a registry. Existing tokens are unaffected.
+        ///
+        /// The dispatch origin for this call must be the registry admin.
+        #[weight = 10_000]
+        pub fn freeze_minting(origin, registry_id: RegistryId) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_registry_admin(&who, registry_id)?;
+
+            MintingFrozen::insert(registry_id, true);
+
+            Self::deposit_event(RawEvent::MintingFrozen(registry_id));
+            Ok(())
+        }
+
+        /// Allow an account to mint in a registry with the allowlist mint policy.
+        ///
+        /// The dispatch origin for this call must be the registry admin.
+        #[weight = 10_000]
+        pub fn add_minter(origin, registry_id: RegistryId, minter: T::AccountId) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_registry_admin(&who, registry_id)?;
+
+            <Minters<T>>::insert(registry_id, &minter, true);
+
+            Self::deposit_event(RawEvent::MinterAdded(registry_id, minter));
+            Ok(())
+        }
+
+        /// Remove an account from the allowlist of minters of a registry.
+        ///
+        /// The dispatch origin for this call must be the registry admin.
+        #[weight = 10_000]
+        pub fn remove_minter(origin, registry_id: RegistryId, minter: T::AccountId) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_registry_admin(&who, registry_id)?;
+
+            <Minters<T>>::remove(registry_id, &minter);
+
+            Self::deposit_event(RawEvent::MinterRemoved(registry_id, minter));
+            Ok(())
+        }
+
+        /// Approve the next transfer of a token to `dest`, or revoke the approval with
+        /// `None`, in a registry with the admin approved transfer policy.
+        ///
+        /// The dispatch origin for this call must be the admin of the registry of the token.
+        #[weight = 10_000]
+        pub fn approve_transfer(origin, commodity_id: AssetId<T>, dest: Option<T::AccountId>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            let registry_id = Self::held_in_registry(&commodity_id).ok_or(Error::<T>::NotInRegistry)?;
+            Self::ensure_registry_admin(&who, registry_id)?;
+
+            match &dest {
+                Some(dest) => <TransferApprovals<T>>::insert(&commodity_id, dest),
+                None => <TransferApprovals<T>>::remove(&commodity_id),
+            }
+
+            Self::deposit_event(RawEvent::TransferApproved(commodity_id, dest));
+            Ok(())
+        }
+
+        /// Allow an account to receive tokens of a registry with the allowlist transfer policy.
+        ///
+        /// The dispatch origin for this call must be the registry admin.
+        #[weight = 10_000]
+        pub fn add_recipient(origin, registry_id: RegistryId, recipient: T::AccountId) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_registry_admin(&who, registry_id)?;
+
+            <Recipients<T>>::insert(registry_id, &recipient, true);
+
+            Self::deposit_event(RawEvent::RecipientAdded(registry_id, recipient));
+            Ok(())
+        }
+
+        /// Remove an account from the allowlist of recipients of a registry.
+        ///
+        /// The dispatch origin for this call must be the registry admin.
+        #[weight = 10_000]
+        pub fn remove_recipient(origin, registry_id: RegistryId, recipient: T::AccountId) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_registry_admin(&who, registry_id)?;
+
+            <Recipients<T>>::remove(registry_id, &recipient);
+
+            Self::deposit_event(RawEvent::RecipientRemoved(registry_id, recipient));
+            Ok(())
+        }
+
+        /// Replace the name, symbol and base URI of a registry.
+        ///
+        /// The dispatch origin for this call must be the registry admin, and the
+        /// metadata of the registry must not be frozen.
+        #[weight = 10_000]
+        pub fn set_registry_metadata(origin,
+                                     registry_id: RegistryId,
+                                     name: bytes,
+                                     symbol: bytes,
+                                     base_uri: bytes,
+        ) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_registry_admin(&who, registry_id)?;
+            ensure!(!Self::metadata_frozen(registry_id), Error::<T>::MetadataFrozen);
+            Self::ensure_valid_metadata(&name, &symbol, &base_uri)?;
+
+            Registries::mutate(registry_id, |info| {
+                info.name = name;
+                info.symbol = symbol;
+                info.base_uri = base_uri;
+            });
+
+            Self::deposit_event(RawEvent::MetadataUpdated(registry_id));
+            Ok(())
+        }
+
+        /// Set the URI of a token, or clear it with `None` to fall back to the base
+        /// URI of its registry.
+        ///
+        /// The dispatch origin for this call must be the admin of the registry of the
+        /// token, and the metadata of the registry must not be frozen.
+        #[weight = 10_000]
+        pub fn set_token_uri(origin, commodity_id: AssetId<T>, uri: Option<bytes>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            let registry_id = Self::held_in_registry(&commodity_id).ok_or(Error::<T>::NotInRegistry)?;
+            Self::ensure_registry_admin(&who, registry_id)?;
+            ensure!(!Self::metadata_frozen(registry_id), Error::<T>::MetadataFrozen);
+
+            match uri {
+                Some(uri) => {
+                    ensure!(uri.len() <= T::MaxUriLength::get() as usize, Error::<T>::UriTooLong);
+                    <TokenUris<T>>::insert(&commodity_id, uri);
+                }
+                None => <TokenUris<T>>::remove(&commodity_id),
+            }
+
+            Self::deposit_event(RawEvent::TokenUriSet(commodity_id));
+            Ok(())
+        }
+
+        /// Permanently freeze the metadata of a registry and the URIs of its tokens.
+        ///
+        /// The dispatch origin for this call must be the registry admin.
+        #[weight = 10_000]
+        pub fn freeze_metadata(origin, registry_id: RegistryId) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_registry_admin(&who, registry_id)?;
+
+            MetadataFrozen::insert(registry_id, true);
+
+            Self::deposit_event(RawEvent::MetadataFrozen(registry_id));
+            Ok(())
+        }
+
+        /// Remove a registry and all of its metadata.
+        ///
+        /// The dispatch origin for this call must be the registry admin, and the
+        /// registry must not hold any tokens.
+        #[weight = 10_000]
+        pub fn destroy_registry(origin, registry_id: RegistryId) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_registry_admin(&who, registry_id)?;
+            ensure!(Self::registry_total(registry_id) == 0, Error::<T>::RegistryNotEmpty);
+
+            Registries::remove(registry_id);
+            <RegistryAdmin<T>>::remove(registry_id);
+            RegistryTotal::remove(registry_id);
+            MintingFrozen::remove(registry_id);
+            MetadataFrozen::remove(registry_id);
+            <RegistryAccountTotal<T>>::remove_prefix(registry_id);
+            <Minters<T>>::remove_prefix(registry_id);
+            <Recipients<T>>::remove_prefix(registry_id);
+            <ConsumedProofs<T>>::remove_prefix(registry_id);
+
+            Self::deposit_event(RawEvent::RegistryDestroyed(registry_id));
+            Ok(())
+        }
+    }
+}
+
+// Query methods of the module, used by the runtime api
+impl<T: Trait> Module<T> {
+    /// The owner of an asset, if it exists.
+    pub fn owner_of(asset_id: &AssetId<T>) -> Option<T::AccountId> {
+        <pallet_nft::Module<T>>::owner_of(asset_id)
+    }
+
+    /// The info of an asset, if it exists.
+    pub fn asset_info(asset_id: &AssetId<T>) -> Option<AssetInfo<T::Hash>> {
+        <pallet_nft::Module<T>>::commodity_info(asset_id)
+    }
+
+    /// The registry an asset belongs to, if it exists.
+    pub fn registry_of(asset_id: &AssetId<T>) -> Option<RegistryId> {
+        <pallet_nft::Module<T>>::registry_of(asset_id)
+    }
+
+    /// Up to `limit` assets owned by an account along with their info, in order of their id,
+    /// starting after the asset id `start_after` when given.
+    pub fn assets_for_account(account: &T::AccountId,
+                              start_after: Option<AssetId<T>>,
+                              limit: u32,
+    ) -> Vec<(AssetId<T>, AssetInfo<T::Hash>)> {
+        <pallet_nft::Module<T>>::commodities_for_account_page(account, start_after, limit)
+            .into_iter()
+            .map(|asset| (asset.id, asset.commodity))
+            .collect()
+    }
+
+    /// The info of a registry, if it exists.
+    pub fn registry_info(registry_id: RegistryId) -> Option<RegistryInfo> {
+        if Registries::contains_key(registry_id) {
+            Some(Registries::get(registry_id))
+        } else {
+            None
+        }
+    }
+
+    /// The URI of an asset: its own token URI when set, otherwise the base URI of its
+    /// registry followed by the lowercase hex of the asset id, if the base URI is set.
+    pub fn asset_uri(asset_id: &AssetId<T>) -> Option<bytes> {
+        if let Some(uri) = Self::token_uri(asset_id) {
+            return Some(uri);
+        }
+
+        let mut uri = Registries::get(Self::registry_of(asset_id)?).base_uri;
+        if uri.is_empty() {
+            return None;
+        }
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        for byte in asset_id.as_bytes() {
+            uri.push(HEX[(byte >> 4) as usize]);
+            uri.push(HEX[(byte & 0xf) as usize]);
+        }
+        Some(uri)
+    }
+
+    /// Whether an asset is held in a registry and still valid: it has not been revoked,
+    /// and when its registry verifies mints against anchors, the anchor it was last
+    /// proven against still exists, has not expired and has not been revoked.
+    pub fn is_live(asset_id: &AssetId<T>) -> bool {
+        let registry_id = match Self::held_in_registry(asset_id) {
+            Some(registry_id) => registry_id,
+            None => return false,
+        };
+        if Self::is_revoked(asset_id) {
+            return false;
+        }
+
+        match Registries::get(registry_id).verifier {
+            VerifierKind::MerkleAnchor => Self::attestation(asset_id)
+                .map_or(false, |attestation| Self::get_live_anchor(attestation.anchor_id).is_ok()),
+            _ => true,
+        }
+    }
+
+    /// Up to `limit` asset ids held in a registry, in order of their id,
+    /// starting after the asset id `start_after` when given.
+    pub fn assets_in_registry(registry_id: RegistryId,
+                              start_after: Option<AssetId<T>>,
+                              limit: u32,
+    ) -> Vec<AssetId<T>> {
+        let prefix = <NftLists<T> as StorageDoubleMapGenerator<_, _, _>>::storage_double_map_final_key1(registry_id);
+        // Continue from the key of the cursor instead of reading every asset before it
+        let mut key = match start_after {
+            Some(start) => NftLists::<T>::hashed_key_for(registry_id, start),
+            None => prefix.clone(),
+        };
+
+        let mut assets = Vec::new();
+        while assets.len() < limit as usize {
+            match sp_io::storage::next_key(&key).filter(|next| next.starts_with(&prefix)) {
+                Some(next) => {
+                    // Asset ids are identity hashed, so the keys end with the id in order of id
+                    if let Ok(asset_id) = AssetId::<T>::decode(&mut &next[prefix.len()..]) {
+                        assets.push(asset_id);
+                    }
+                    key = next;
+                }
+                None => break,
+            }
+        }
+        assets
     }
 }
 
 // Auxillary methods of the module for internal use
 impl<T: Trait> Module<T> {
-    fn get_document_root(anchor_id: T::Hash) -> Result<T::Hash, dispatch::DispatchError> {
-        //match <anchor::Module<T>>::get_anchor_by_id(*anchor_id) {
-        match Self::get_anchor_by_id(anchor_id) {
-            //Some(anchor_data) => Ok(anchor_data.doc_root),
-            Some(anchor_data) => Ok(anchor_data),
-            None => Err(Error::<T>::DocumentNotAnchored.into()),
+    pub(crate) fn get_document_root(anchor_id: T::Hash) -> Result<T::Hash, dispatch::DispatchError> {
+        Ok(Self::get_live_anchor(anchor_id)?.doc_root)
+    }
+
+    /// The anchor with the given id, if it has been committed, has not expired and has
+    /// not been revoked.
+    fn get_live_anchor(anchor_id: T::Hash)
+        -> Result<AnchorData<T::Hash, T::AccountId, T::BlockNumber>, dispatch::DispatchError>
+    {
+        let anchor_data = T::Anchors::get_anchor_by_id(anchor_id).ok_or(Error::<T>::DocumentNotAnchored)?;
+        ensure!(
+            anchor_data.expiration_block >= <frame_system::Module<T>>::block_number(),
+            Error::<T>::AnchorExpired
+        );
+        ensure!(!anchor_data.revoked, Error::<T>::AnchorRevoked);
+        Ok(anchor_data)
+    }
+
+    /// The key under which the proofs of the leaves against an anchored document root
+    /// are recorded as consumed.
+    fn proof_key(doc_root: T::Hash, leaves: &[T::Hash]) -> T::Hash {
+        T::Hashing::hash_of(&(doc_root, leaves))
+    }
+
+    /// The leaf hashes of the salted values of a mint for the given fields.
+    fn leaves_of(fields: Vec<bytes>, mint_info: &MintInfo<T::Hash>) -> Result<Vec<T::Hash>, dispatch::DispatchError> {
+        // The number of values passed in should match the number of fields for the registry
+        ensure!(
+            fields.len() == mint_info.values.len(),
+            Error::<T>::InvalidMintingValues
+        );
+        // Each value is salted
+        ensure!(
+            mint_info.values.len() == mint_info.salts.len(),
+            Error::<T>::InvalidMintingSalts
+        );
+
+        Ok(fields.into_iter()
+            .zip(mint_info.values.iter().cloned())
+            .zip(mint_info.salts.iter().cloned())
+            .map(|((field, val), salt)|
+                Self::leaf_hash(field, val, salt))
+            .collect())
+    }
+
+    /// Ensure the registry exists and `who` is its admin.
+    fn ensure_registry_admin(who: &T::AccountId, registry_id: RegistryId) -> dispatch::DispatchResult {
+        ensure!(Registries::contains_key(registry_id), Error::<T>::RegistryDoesNotExist);
+        ensure!(Self::registry_admin(registry_id).as_ref() == Some(who), Error::<T>::NotRegistryAdmin);
+        Ok(())
+    }
+
+    /// The registry of an asset, if the asset was minted through the registry. Assets
+    /// minted by pallet_nft directly are not accounted for by their registry.
+    fn held_in_registry(asset_id: &AssetId<T>) -> Option<RegistryId> {
+        Self::registry_of(asset_id).filter(|registry_id| NftLists::<T>::contains_key(registry_id, asset_id))
+    }
+
+    /// Ensure the mint policy of the registry allows `who` to mint to `owner_account`.
+    fn ensure_may_mint(who: &T::AccountId,
+                       owner_account: &T::AccountId,
+                       registry_id: RegistryId,
+                       mint_info: &MintInfo<T::Hash>,
+    ) -> dispatch::DispatchResult {
+        ensure!(Registries::contains_key(registry_id), Error::<T>::RegistryDoesNotExist);
+
+        match Registries::get(registry_id).mint_policy {
+            MintPolicy::Anyone => {}
+            MintPolicy::AdminOnly => ensure!(
+                Self::registry_admin(registry_id).as_ref() == Some(who),
+                Error::<T>::MintRequiresAdmin
+            ),
+            MintPolicy::Allowlist => ensure!(
+                Self::is_minter(registry_id, who),
+                Error::<T>::NotAllowedMinter
+            ),
+            MintPolicy::AnchorOwnerToSelf => {
+                let anchor = T::Anchors::get_anchor_by_id(mint_info.anchor_id)
+                    .ok_or(Error::<T>::DocumentNotAnchored)?;
+                ensure!(anchor.owner == *who, Error::<T>::NotAnchorOwner);
+                ensure!(owner_account == who, Error::<T>::CanOnlyMintToSelf);
+            }
         }
+        Ok(())
+    }
+
+    /// Ensure the mint policy of a registry can be enforced with its verifier. Only
+    /// mints verified against anchors prove who owns their anchor.
+    fn ensure_valid_mint_policy(info: &RegistryInfo) -> dispatch::DispatchResult {
+        ensure!(
+            info.mint_policy != MintPolicy::AnchorOwnerToSelf || info.verifier == VerifierKind::MerkleAnchor,
+            Error::<T>::MintPolicyRequiresAnchors
+        );
+        Ok(())
+    }
+
+    /// Ensure the metadata of a registry is within the configured bounds.
+    fn ensure_valid_metadata(name: &bytes, symbol: &bytes, base_uri: &bytes) -> dispatch::DispatchResult {
+        ensure!(name.len() <= T::MaxNameLength::get() as usize, Error::<T>::NameTooLong);
+        ensure!(symbol.len() <= T::MaxSymbolLength::get() as usize, Error::<T>::SymbolTooLong);
+        ensure!(base_uri.len() <= T::MaxUriLength::get() as usize, Error::<T>::UriTooLong);
+        Ok(())
     }
 
     fn create_new_registry_id() -> Result<RegistryId, dispatch::DispatchError> {
@@ -187,11 +880,17 @@ impl<T: Trait> Module<T> {
         Ok(id)
     }
 
-    /// Generates a hash of the concatenated inputs, consuming inputs in the process.
-    fn leaf_hash(mut field: bytes, mut value: bytes/*, salt: u32*/) -> T::Hash {
-        // Generate leaf hash from field ++ value
-        let leaf_data = field.extend(value);
-        <T as frame_system::Trait>::Hashing::hash_of(&leaf_data)
+    /// Generates the leaf hash of a field and its salted value, consuming inputs in the process.
+    ///
+    /// The leaf is the runtime hash (blake2-256) of the raw bytes `field ++ value ++ salt`,
+    /// without length prefixes or any other encoding, as produced for the leaves of
+    /// the off-chain document tree.
+    fn leaf_hash(field: bytes, value: bytes, salt: Salt) -> T::Hash {
+        // Generate leaf hash from field ++ value ++ salt
+        let mut leaf_data = field;
+        leaf_data.extend(value);
+        leaf_data.extend_from_slice(&salt);
+        <T as frame_system::Trait>::Hashing::hash(&leaf_data)
     }
 }
 
@@ -202,7 +901,7 @@ impl<T: Trait> VerifierRegistry for Module<T> {
     type RegistryId   = RegistryId;
     type RegistryInfo = RegistryInfo;
     type AssetId      = AssetId<T>;
-    type AssetInfo    = <T as pallet_nft::Trait>::CommodityInfo;
+    type AssetInfo    = AssetInfo<<T as frame_system::Trait>::Hash>;
     type MintInfo     = MintInfo<<T as frame_system::Trait>::Hash>;
 
     // Registries with identical RegistryInfo may exist
@@ -217,10 +916,9 @@ impl<T: Trait> VerifierRegistry for Module<T> {
     }
 
     fn mint(owner_account: <T as frame_system::Trait>::AccountId,
-            commodity_info: T::CommodityInfo,
+            registry_id: RegistryId,
             mint_info: MintInfo<<T as frame_system::Trait>::Hash>,
     ) -> Result<Self::AssetId, dispatch::DispatchError> {
-        let registry_id = commodity_info.registry_id();
         let registry_info = Registries::get(registry_id);
 
         // Check that the registry exists
@@ -230,42 +928,134 @@ impl<T: Trait> VerifierRegistry for Module<T> {
             Error::<T>::RegistryDoesNotExist
         );
 
-        let fields = registry_info.fields;
-        let values = mint_info.values;
-        // The number of values passed in should match the number of fields for the registry
         ensure!(
-            fields.len() == values.len(),
-            Error::<T>::InvalidMintingValues
+            !Self::minting_frozen(registry_id),
+            Error::<T>::RegistryFrozen
         );
 
-        // -------------
-        // Verify proofs
+        // The registry caps apply on top of the global limits of pallet_nft
+        ensure!(
+            registry_info.max_supply.map_or(true, |max| Self::registry_total(registry_id) < max),
+            Error::<T>::RegistrySupplyExceeded
+        );
+        ensure!(
+            registry_info.max_per_account.map_or(true, |max| Self::registry_account_total(registry_id, &owner_account) < max),
+            Error::<T>::TooManyTokensForAccount
+        );
 
-        // Get the doc root
-        // TODO: Use this line instead, once integrated with cent chain
-        // let anchor_data = <anchor::Module<T>>::get_anchor_by_id(anchor_id).ok_or("Anchor doesn't exist")?;
-        /*** Tmp replacement for tests: ***/
-        let doc_root = Self::get_document_root(mint_info.anchor_id)?;
+        // Generate leaf hashes of each value for verification
+        let leaves = Self::leaves_of(registry_info.fields.clone(), &mint_info)?;
 
-        // Generate leaf hashes of each value for proof
-        let leaves = fields.into_iter()
-            .zip(values)
-            .map(|(field, val)|
-                Self::leaf_hash(field, val));
+        // Verify the leaves with the verifier of the registry
+        MintVerifier::<T>::verify(&registry_info.verifier, registry_id, &owner_account, &leaves, &mint_info)?;
 
-        // Verify the proof against document root
-        // TODO: Once integrated w/ cent chain
-        //Self::validate_proofs(&doc_root, &proofs, &static_proofs)?;
+        // A proven document or an attestation mints a single token of the registry, and
+        // tokens proven against an anchor are valid until the anchor expires
+        let (proof_key, attestation) = match registry_info.verifier {
+            VerifierKind::MerkleAnchor => {
+                let anchor_data = Self::get_live_anchor(mint_info.anchor_id)?;
+                (Some(Self::proof_key(anchor_data.doc_root, &leaves)),
+                 Some(Attestation { anchor_id: anchor_data.id, valid_until: anchor_data.expiration_block }))
+            }
+            VerifierKind::Attester(_) => {
+                let message = AttesterVerifier::message(registry_id, &owner_account, &mint_info.anchor_id, mint_info.nonce, &leaves);
+                (Some(T::Hashing::hash(&message)), None)
+            }
+            VerifierKind::Open => (None, None),
+        };
+        if let Some(proof_key) = proof_key {
+            ensure!(!Self::is_proof_consumed(registry_id, proof_key), Error::<T>::ProofAlreadyUsed);
+        }
 
         // -------
         // Minting
 
+        // The asset keeps what was verified about it
+        let asset_info = AssetInfo {
+            registry_id,
+            anchor_id: attestation.as_ref().map(|attestation| attestation.anchor_id),
+            fields: registry_info.fields,
+            values: mint_info.values,
+        };
+
         // Internal nft mint
-        let commodity_id = <pallet_nft::Module<T>>::mint(&owner_account, commodity_info)?;
+        let commodity_id = <pallet_nft::Module<T> as Mintable>::mint(&owner_account, asset_info)?;
 
         // Place asset id in registry map
         NftLists::<T>::insert(registry_id, commodity_id, ());
+        RegistryTotal::mutate(registry_id, |total| *total += 1);
+        <RegistryAccountTotal<T>>::mutate(registry_id, &owner_account, |total| *total += 1);
+        if let Some(proof_key) = proof_key {
+            <ConsumedProofs<T>>::insert(registry_id, proof_key, true);
+        }
+        if let Some(attestation) = attestation {
+            <Attestations<T>>::insert(commodity_id, attestation);
+        }
 
         Ok(commodity_id)
     }
 }
+
+// Enforce the transfer policy and per account cap of a registry on transfers, which
+// may also be made through pallet_nft directly.
+impl<T: Trait> pallet_nft::TransferHook<T::AccountId, AssetId<T>> for Module<T> {
+    fn can_transfer(from: &T::AccountId, to: &T::AccountId, asset_id: &AssetId<T>) -> dispatch::DispatchResult {
+        if let Some(registry_id) = Self::held_in_registry(asset_id) {
+            ensure!(!Self::is_revoked(asset_id), Error::<T>::TokenRevoked);
+            let registry_info = Registries::get(registry_id);
+
+            match registry_info.transfer_policy {
+                TransferPolicy::Free => {}
+                TransferPolicy::NonTransferable => return Err(Error::<T>::NonTransferable.into()),
+                TransferPolicy::AdminApproved => ensure!(
+                    Self::transfer_approval(asset_id).as_ref() == Some(to),
+                    Error::<T>::TransferNotApproved
+                ),
+                TransferPolicy::Allowlist => ensure!(
+                    Self::is_recipient(registry_id, to),
+                    Error::<T>::RecipientNotAllowed
+                ),
+            }
+
+            if let Some(max) = registry_info.max_per_account {
+                ensure!(
+                    from == to || Self::registry_account_total(registry_id, to) < max,
+                    Error::<T>::TooManyTokensForAccount
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn on_transfer(from: &T::AccountId, to: &T::AccountId, asset_id: &AssetId<T>) {
+        if let Some(registry_id) = Self::held_in_registry(asset_id) {
+            <RegistryAccountTotal<T>>::mutate(registry_id, from, |total| *total -= 1);
+            <RegistryAccountTotal<T>>::mutate(registry_id, to, |total| *total += 1);
+            // An approval is good for a single transfer
+            <TransferApprovals<T>>::remove(asset_id);
+        }
+    }
+}
+
+// Enforce whether owners may burn tokens of a registry, and keep the registry accounting
+// on burns, which may also be made through pallet_nft directly.
+impl<T: Trait> pallet_nft::BurnHook<T::AccountId, AssetId<T>> for Module<T> {
+    fn can_burn(_owner: &T::AccountId, asset_id: &AssetId<T>) -> dispatch::DispatchResult {
+        if let Some(registry_id) = Self::held_in_registry(asset_id) {
+            ensure!(Registries::get(registry_id).owner_can_burn, Error::<T>::OwnerCannotBurn);
+        }
+        Ok(())
+    }
+
+    fn on_burn(owner: &T::AccountId, asset_id: &AssetId<T>) {
+        if let Some(registry_id) = Self::held_in_registry(asset_id) {
+            NftLists::<T>::remove(registry_id, asset_id);
+            <RegistryAccountTotal<T>>::mutate(registry_id, owner, |total| *total -= 1);
+            <TokenUris<T>>::remove(asset_id);
+            <TransferApprovals<T>>::remove(asset_id);
+            <Revoked<T>>::remove(asset_id);
+            <Attestations<T>>::remove(asset_id);
+            RegistryTotal::mutate(registry_id, |total| *total -= 1);
+        }
+    }
+}
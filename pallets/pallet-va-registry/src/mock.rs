@@ -56,14 +56,37 @@ parameter_types! {
 
 impl pallet_nft::Trait for Test {
     type Event = ();
+    type CommodityAdmin = frame_system::EnsureRoot<u64>;
     //type CommodityInfo = Vec<u8>;
-    type CommodityInfo = crate::AssetInfo;
+    type CommodityInfo = crate::AssetInfo<H256>;
     type CommodityLimit = MaxCommodities;
     type UserCommodityLimit = MaxCommoditiesPerUser;
+    type IdStrategy = pallet_nft::ContentHashWithNonce;
+    type TransferHook = Module<Test>;
+    type BurnHook = Module<Test>;
+}
+
+parameter_types! {
+    pub const PreCommitExpiration: u64 = 10;
+}
+
+impl pallet_anchor::Trait for Test {
+    type Event = ();
+    type PreCommitExpiration = PreCommitExpiration;
+}
+
+parameter_types! {
+    pub const MaxNameLength: u32 = 8;
+    pub const MaxSymbolLength: u32 = 4;
+    pub const MaxUriLength: u32 = 32;
 }
 
 impl Trait for Test {
     type Event = ();
+    type Anchors = pallet_anchor::Module<Test>;
+    type MaxNameLength = MaxNameLength;
+    type MaxSymbolLength = MaxSymbolLength;
+    type MaxUriLength = MaxUriLength;
 }
 
 // System Under Test
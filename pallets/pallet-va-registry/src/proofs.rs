@@ -2,7 +2,7 @@ use codec::{Decode, Encode};
 use sp_core::H256;
 use sp_std::vec::Vec;
 
-#[derive(Encode, Decode, Default, Clone, PartialEq)]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
 #[cfg_attr(not(feature = "std"), derive(sp_runtime::RuntimeDebug))]
 #[cfg_attr(feature = "std", derive(Debug))]
 pub struct Proof {
@@ -51,7 +51,7 @@ pub fn validate_proofs(doc_root: H256, proofs: &Vec<Proof>, static_proofs: [H256
 // computes blake2 256 sorted hash of the a and b
 // if a < b: blake256(a+b)
 // else: blake256(b+a)
-fn sort_hash_of(a: H256, b: H256) -> H256 {
+pub(crate) fn sort_hash_of(a: H256, b: H256) -> H256 {
     let mut h: Vec<u8> = Vec::with_capacity(64);
     if a < b {
         h.extend_from_slice(&a[..]);
@@ -65,7 +65,7 @@ fn sort_hash_of(a: H256, b: H256) -> H256 {
 }
 
 // computes blake2 256 hash of the a + b
-fn hash_of(a: H256, b: H256) -> H256 {
+pub(crate) fn hash_of(a: H256, b: H256) -> H256 {
     let mut h: Vec<u8> = Vec::with_capacity(64);
     h.extend_from_slice(&a[..]);
     h.extend_from_slice(&b[..]);
@@ -1,120 +1,123 @@
 use crate::{Error, mock::*};
-use crate::proofs::Proof;
+use crate::proofs::{hash_of, sort_hash_of, Proof};
 use sp_core::{H256, Encode};
-use frame_support::{assert_ok, Hashable};
+use frame_support::{assert_noop, assert_ok, Hashable};
 use sp_runtime::{
     testing::Header,
     traits::{BadOrigin, BlakeTwo256, Hash, IdentityLookup, Block as BlockT},
 };
 use sp_core::hashing::blake2_128;
+use sp_core::{sr25519, Pair};
 use super::*;
 
 
-fn get_valid_proof() -> (Proof, sp_core::H256, [H256; 3]) {
-   let proof = Proof::new(
-       [
-           1, 93, 41, 93, 124, 185, 25, 20, 141, 93, 101, 68, 16, 11, 142, 219, 3, 124, 155,
-           37, 85, 23, 189, 209, 48, 97, 34, 3, 169, 157, 88, 159,
-       ]
-       .into(),
-       vec![
-           [
-               113, 229, 58, 223, 178, 220, 200, 69, 191, 246, 171, 254, 8, 183, 211, 75, 54,
-               223, 224, 197, 170, 112, 248, 56, 10, 176, 17, 205, 86, 130, 233, 16,
-           ]
-           .into(),
-           [
-               133, 11, 212, 75, 212, 65, 247, 178, 200, 157, 5, 39, 57, 135, 63, 126, 166,
-               92, 232, 170, 46, 155, 223, 237, 50, 237, 43, 101, 180, 104, 126, 84,
-           ]
-           .into(),
-           [
-               197, 248, 165, 165, 247, 119, 114, 231, 95, 114, 94, 16, 66, 142, 230, 184, 78,
-               203, 73, 104, 24, 82, 134, 154, 180, 129, 71, 223, 72, 31, 230, 15,
-           ]
-           .into(),
-           [
-               50, 5, 28, 219, 118, 141, 222, 221, 133, 174, 178, 212, 71, 94, 64, 44, 80,
-               218, 29, 92, 77, 40, 241, 16, 126, 48, 119, 31, 6, 147, 224, 5,
-           ]
-           .into(),
-       ],
-   );
-   let doc_root: H256 = [
-       48, 123, 58, 192, 8, 62, 20, 55, 99, 52, 37, 73, 174, 123, 214, 104, 37, 41, 189, 170,
-       205, 80, 158, 136, 224, 128, 128, 89, 55, 240, 32, 234,
-   ]
-   .into();
-
-   let static_proofs: [H256; 3] = [
-       [
-           25, 102, 189, 46, 86, 242, 48, 217, 254, 16, 20, 211, 98, 206, 125, 92, 167, 175,
-           70, 161, 35, 135, 33, 80, 225, 247, 4, 240, 138, 86, 167, 142,
-       ]
-       .into(),
-       [
-           61, 164, 199, 22, 164, 251, 58, 14, 67, 56, 242, 60, 86, 203, 128, 203, 138, 129,
-           237, 7, 29, 7, 39, 58, 250, 42, 14, 53, 241, 108, 187, 74,
-       ]
-       .into(),
-       [
-           70, 124, 133, 120, 103, 45, 94, 174, 176, 18, 151, 243, 104, 120, 12, 54, 217, 189,
-           59, 222, 109, 64, 136, 203, 56, 136, 159, 115, 96, 101, 2, 185,
-       ]
-       .into(),
-   ];
-
-   (proof, doc_root, static_proofs)
+// Build proofs for two leaves, where the basic data root is the hash of both leaves.
+fn get_valid_proofs(leaves: (H256, H256)) -> (Vec<Proof>, H256, [H256; 3]) {
+    let (a, b) = leaves;
+    let basic_data_root = sort_hash_of(a, b);
+    let zk_data_root = H256::repeat_byte(1);
+    let signature_root = H256::repeat_byte(2);
+    let doc_root = hash_of(hash_of(basic_data_root, zk_data_root), signature_root);
+
+    (vec![Proof::new(a, vec![b]), Proof::new(b, vec![a])],
+     doc_root,
+     [basic_data_root, zk_data_root, signature_root])
+}
+
+// Leaves for the given fields and salted values, as computed by the registry.
+fn leaves_of(fields: &Vec<Vec<u8>>, values: &Vec<Vec<u8>>, salts: &Vec<Salt>) -> (H256, H256) {
+    (SUT::leaf_hash(fields[0].clone(), values[0].clone(), salts[0]),
+     SUT::leaf_hash(fields[1].clone(), values[1].clone(), salts[1]))
+}
+
+// Create a registry with two fields as account 1, with the rest of its info set by `f`
+// on top of the defaults. Returns the id of the new registry.
+fn create_registry_with(f: impl FnOnce(&mut RegistryInfo)) -> RegistryId {
+    let mut info = RegistryInfo {
+        fields: vec![vec![0], vec![1]],
+        ..Default::default()
+    };
+    f(&mut info);
+
+    let registry_id = RegistryNonce::get();
+    assert_ok!(SUT::create_registry(Origin::signed(1), info));
+    registry_id
 }
 
+// Create a registry with two fields as account 1 and anchor a document for the given values.
+// Returns the registry id, anchor id and the mint info for the values.
+fn setup_mint(owner_can_burn: bool, values: Vec<Vec<u8>>) -> (RegistryId, H256, MintInfo<H256>) {
+    let origin = Origin::signed(1);
+    let fields = vec![vec![0], vec![1]];
+    let salts = vec![[1; 32], [2; 32]];
+    let (proofs, doc_root, static_proofs) = get_valid_proofs(leaves_of(&fields, &values, &salts));
+    let pre_image = <Test as frame_system::Trait>::Hashing::hash_of(&0);
+    let anchor_id = (pre_image).using_encoded(<Test as frame_system::Trait>::Hashing::hash);
+
+    let registry_id = create_registry_with(|info| {
+        info.owner_can_burn = owner_can_burn;
+        info.fields = fields;
+        info.verifier = VerifierKind::MerkleAnchor;
+    });
+
+    // Anchor the document for verification
+    assert_ok!(<pallet_anchor::Module<Test>>::commit(origin, pre_image, doc_root, H256::zero(), 100));
+
+    (registry_id,
+     anchor_id,
+     MintInfo {
+         anchor_id: anchor_id,
+         proofs: proofs,
+         static_proofs: static_proofs,
+         values: values,
+         salts: salts,
+         signature: None,
+         nonce: 0,
+     })
+}
+
+// The ids of all assets held in a registry.
+fn assets_of(registry_id: RegistryId) -> Vec<H256> {
+    SUT::assets_in_registry(registry_id, None, u32::max_value())
+}
 
 #[test]
 fn mint_with_valid_proofs_works() {
     new_test_ext().execute_with(|| {
         let owner     = 1;
         let origin    = Origin::signed(1);
-        let (pf, doc_root, static_proofs) = get_valid_proof();
-        let pre_image = <Test as frame_system::Trait>::Hashing::hash_of(&0);
-        let anchor_id = (pre_image).using_encoded(<Test as frame_system::Trait>::Hashing::hash);
-        let fields = vec![vec![0], vec![1]];
         let values = vec![vec![2], vec![3]];
 
-        let registry_id = 0;
-        let nft_data = AssetInfo {
-            registry_id,
-        };
-        let registry_info = RegistryInfo {
-            owner_can_burn: false,
-            fields: fields,
-        };
-
         // Starts with no Nfts
         assert_eq!(<pallet_nft::Module<Test>>::total(), 0);
         assert_eq!(<pallet_nft::Module<Test>>::total_for_account(owner), 0);
 
-        // Create registry
-        assert_ok!(
-            SUT::create_registry(origin.clone(), registry_info)
-        );
-
-        // Place document anchor into storage for verification
-        assert_ok!(SUT::tmp_set_anchor(origin.clone(), anchor_id, doc_root));
+        // Create registry and anchor the document
+        let (registry_id, anchor_id, mint_info) = setup_mint(false, values.clone());
 
         // Mint token with document proof
         assert_ok!(
             SUT::mint(origin,
                       owner,
-                      nft_data.clone(),
-                      MintInfo {
-                          anchor_id: anchor_id,
-                          proofs: vec![pf],
-                          values: values,
-                      }));
+                      registry_id,
+                      mint_info));
 
         // Nft registered to owner
+        let commodity_id = assets_of(registry_id)[0];
         assert_eq!(
-            <pallet_nft::Module<Test>>::account_for_commodity::<H256>(<Test as frame_system::Trait>::Hashing::hash_of(&nft_data)),
-            owner
+            <pallet_nft::Module<Test>>::account_for_commodity::<H256>(commodity_id),
+            Some(owner)
+        );
+
+        // Nft holds the verified values
+        assert_eq!(
+            SUT::asset_info(&commodity_id),
+            Some(AssetInfo {
+                registry_id,
+                anchor_id: Some(anchor_id),
+                fields: vec![vec![0], vec![1]],
+                values,
+            })
         );
 
         // Total Nfts did increase
@@ -123,9 +126,1102 @@ fn mint_with_valid_proofs_works() {
     });
 }
 
+#[test]
+fn leaf_hash_matches_test_vectors() {
+    // blake2-256 of [0, 2] ++ [1; 32]
+    assert_eq!(
+        SUT::leaf_hash(vec![0], vec![2], [1; 32]),
+        H256::from([
+            115, 206, 229, 1, 86, 143, 68, 171, 76, 57, 125, 30, 47, 174, 94, 247,
+            61, 103, 103, 243, 141, 60, 150, 3, 126, 10, 211, 152, 213, 9, 217, 198,
+        ])
+    );
+    // blake2-256 of "amount100" ++ [0; 32]
+    assert_eq!(
+        SUT::leaf_hash(b"amount".to_vec(), b"100".to_vec(), [0; 32]),
+        H256::from([
+            216, 73, 95, 108, 134, 85, 53, 85, 61, 146, 25, 124, 147, 176, 79, 222,
+            114, 197, 16, 151, 223, 133, 40, 35, 3, 220, 43, 16, 176, 65, 74, 105,
+        ])
+    );
+    // blake2-256 of [0; 32]
+    assert_eq!(
+        SUT::leaf_hash(vec![], vec![], [0; 32]),
+        H256::from([
+            137, 235, 13, 106, 138, 105, 29, 174, 44, 209, 94, 208, 54, 153, 49, 206,
+            10, 148, 158, 202, 250, 92, 63, 147, 248, 18, 24, 51, 100, 110, 21, 195,
+        ])
+    );
+}
+
+#[test]
+fn leaf_hash_depends_on_field_value_and_salt() {
+    assert_ne!(SUT::leaf_hash(vec![0], vec![2], [0; 32]), SUT::leaf_hash(vec![0], vec![3], [0; 32]));
+    assert_ne!(SUT::leaf_hash(vec![0], vec![2], [0; 32]), SUT::leaf_hash(vec![1], vec![2], [0; 32]));
+    assert_ne!(SUT::leaf_hash(vec![0], vec![2], [0; 32]), SUT::leaf_hash(vec![0], vec![2], [1; 32]));
+}
+
+#[test]
+fn mint_with_unproven_values_fails() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, _, mut mint_info) = setup_mint(false, vec![vec![2], vec![3]]);
+
+        // Values differ from the ones in the anchored document
+        mint_info.values = vec![vec![2], vec![4]];
+
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 1, registry_id, mint_info),
+            Error::<Test>::InvalidProofs
+        );
+    });
+}
+
+#[test]
+fn mint_with_wrong_salt_fails() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, _, mut mint_info) = setup_mint(false, vec![vec![2], vec![3]]);
+
+        mint_info.salts[1] = [3; 32];
+
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 1, registry_id, mint_info),
+            Error::<Test>::InvalidProofs
+        );
+    });
+}
+
+#[test]
+fn mint_with_missing_salts_fails() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, _, mut mint_info) = setup_mint(false, vec![vec![2], vec![3]]);
+
+        mint_info.salts.pop();
+
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 1, registry_id, mint_info),
+            Error::<Test>::InvalidMintingSalts
+        );
+    });
+}
+
+#[test]
+fn mint_with_proof_of_other_leaf_fails() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, _, mut mint_info) = setup_mint(false, vec![vec![2], vec![3]]);
+
+        // A valid proof, but not for the leaf of the first value
+        let (other_proofs, _, _) = get_valid_proofs((H256::repeat_byte(4), H256::repeat_byte(5)));
+        mint_info.proofs[0] = other_proofs[0].clone();
+
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 1, registry_id, mint_info),
+            Error::<Test>::InvalidProofs
+        );
+    });
+}
+
+#[test]
+fn mint_without_anchor_fails() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, _, mut mint_info) = setup_mint(false, vec![vec![2], vec![3]]);
+
+        mint_info.anchor_id = H256::repeat_byte(7);
+
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 1, registry_id, mint_info),
+            Error::<Test>::DocumentNotAnchored
+        );
+    });
+}
+
+#[test]
+fn mint_with_wrong_static_proofs_fails() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, _, mut mint_info) = setup_mint(false, vec![vec![2], vec![3]]);
+
+        // Static proofs don't hash to the anchored document root
+        mint_info.static_proofs[2] = H256::repeat_byte(3);
+
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 1, registry_id, mint_info),
+            Error::<Test>::InvalidProofs
+        );
+    });
+}
+
+#[test]
+fn mint_with_missing_proofs_fails() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, _, mut mint_info) = setup_mint(false, vec![vec![2], vec![3]]);
+
+        // Only one of the two values is proven
+        mint_info.proofs.pop();
+
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 1, registry_id, mint_info),
+            Error::<Test>::InvalidProofs
+        );
+    });
+}
+
+// Mint a token to account 2 in a new registry administered by account 1.
+fn mint_to_owner(owner_can_burn: bool) -> (RegistryId, H256) {
+    let (registry_id, _, mint_info) = setup_mint(owner_can_burn, vec![vec![2], vec![3]]);
+    assert_ok!(SUT::mint(Origin::signed(1), 2, registry_id, mint_info));
+
+    (registry_id, assets_of(registry_id)[0])
+}
+
+#[test]
+fn mint_identical_asset_info_twice_works() {
+    new_test_ext().execute_with(|| {
+        let registry_id = create_registry_with(|info| info.verifier = VerifierKind::Open);
+        let mint_info = unproven_mint_info(vec![vec![2], vec![3]]);
+
+        assert_ok!(SUT::mint(Origin::signed(1), 1, registry_id, mint_info.clone()));
+        assert_ok!(SUT::mint(Origin::signed(1), 2, registry_id, mint_info));
+
+        let ids = assets_of(registry_id);
+        assert_eq!(ids.len(), 2);
+        assert_ne!(ids[0], ids[1]);
+        assert_eq!(SUT::registry_total(registry_id), 2);
+    });
+}
+
+#[test]
+fn mint_with_replayed_proofs_fails() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, _, mint_info) = setup_mint(false, vec![vec![2], vec![3]]);
+
+        assert_ok!(SUT::mint(Origin::signed(1), 1, registry_id, mint_info.clone()));
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 2, registry_id, mint_info.clone()),
+            Error::<Test>::ProofAlreadyUsed
+        );
+
+        // Burning the token does not free up the proofs
+        assert_ok!(SUT::burn(Origin::signed(1), assets_of(registry_id)[0]));
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 2, registry_id, mint_info.clone()),
+            Error::<Test>::ProofAlreadyUsed
+        );
+
+        // Nor does anchoring the same document again, by any account
+        let pre_image = <Test as frame_system::Trait>::Hashing::hash_of(&1);
+        let new_anchor_id = (pre_image).using_encoded(<Test as frame_system::Trait>::Hashing::hash);
+        let doc_root = <pallet_anchor::Module<Test>>::get_anchor(mint_info.anchor_id).unwrap().doc_root;
+        assert_ok!(<pallet_anchor::Module<Test>>::commit(Origin::signed(2), pre_image, doc_root, H256::zero(), 100));
+        assert_noop!(
+            SUT::mint(Origin::signed(2), 2, registry_id, MintInfo { anchor_id: new_anchor_id, ..mint_info }),
+            Error::<Test>::ProofAlreadyUsed
+        );
+    });
+}
+
+#[test]
+fn transfer_by_owner_works() {
+    new_test_ext().execute_with(|| {
+        let (_, commodity_id) = mint_to_owner(false);
+
+        assert_ok!(SUT::transfer(Origin::signed(2), 3, commodity_id));
+
+        assert_eq!(SUT::owner_of(&commodity_id), Some(3));
+        assert_eq!(<pallet_nft::Module<Test>>::total_for_account(2), 0);
+        assert_eq!(<pallet_nft::Module<Test>>::total_for_account(3), 1);
+    });
+}
+
+#[test]
+fn transfer_by_approved_account_works() {
+    new_test_ext().execute_with(|| {
+        let (_, commodity_id) = mint_to_owner(false);
+
+        assert_noop!(
+            SUT::transfer(Origin::signed(3), 3, commodity_id),
+            pallet_nft::Error::<Test>::NotApproved
+        );
+
+        assert_ok!(SUT::approve(Origin::signed(2), Some(3), commodity_id));
+        assert_ok!(SUT::transfer(Origin::signed(3), 4, commodity_id));
+
+        assert_eq!(SUT::owner_of(&commodity_id), Some(4));
+        assert_eq!(<pallet_nft::Module<Test>>::approved(&commodity_id), None);
+    });
+}
+
+#[test]
+fn transfer_by_operator_works() {
+    new_test_ext().execute_with(|| {
+        let (_, commodity_id) = mint_to_owner(false);
+
+        assert_ok!(SUT::set_approval_for_all(Origin::signed(2), 3, true));
+        assert_ok!(SUT::transfer(Origin::signed(3), 3, commodity_id));
+        assert_eq!(SUT::owner_of(&commodity_id), Some(3));
+
+        assert_noop!(
+            SUT::approve(Origin::signed(2), Some(2), commodity_id),
+            pallet_nft::Error::<Test>::NotApproved
+        );
+    });
+}
+
+#[test]
+fn burn_by_owner_works() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, commodity_id) = mint_to_owner(true);
+
+        assert_ok!(SUT::burn(Origin::signed(2), commodity_id));
+
+        assert!(!NftLists::<Test>::contains_key(registry_id, commodity_id));
+        assert_eq!(<pallet_nft::Module<Test>>::total(), 0);
+        assert_eq!(<pallet_nft::Module<Test>>::burned(), 1);
+        assert_eq!(<pallet_nft::Module<Test>>::total_for_account(2), 0);
+    });
+}
+
+#[test]
+fn burn_by_owner_without_owner_can_burn_fails() {
+    new_test_ext().execute_with(|| {
+        let (_, commodity_id) = mint_to_owner(false);
+
+        assert_noop!(
+            SUT::burn(Origin::signed(2), commodity_id),
+            Error::<Test>::OwnerCannotBurn
+        );
+    });
+}
+
+#[test]
+fn burn_by_admin_works() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, commodity_id) = mint_to_owner(false);
+
+        assert_ok!(SUT::burn(Origin::signed(1), commodity_id));
+
+        assert!(!NftLists::<Test>::contains_key(registry_id, commodity_id));
+        assert_eq!(<pallet_nft::Module<Test>>::total(), 0);
+    });
+}
+
+#[test]
+fn burn_by_other_account_fails() {
+    new_test_ext().execute_with(|| {
+        let (_, commodity_id) = mint_to_owner(true);
+
+        assert_noop!(
+            SUT::burn(Origin::signed(3), commodity_id),
+            Error::<Test>::NotCommodityOwner
+        );
+    });
+}
+
+#[test]
+fn burn_outside_of_registry_fails() {
+    new_test_ext().execute_with(|| {
+        let registry_id = create_registry_with(|_| {});
+        // Minted without going through the registry
+        let commodity_id = <pallet_nft::Module<Test> as Mintable>::mint(&1, AssetInfo { registry_id, ..Default::default() }).unwrap();
+
+        assert_noop!(
+            SUT::burn(Origin::signed(1), commodity_id),
+            Error::<Test>::NotInRegistry
+        );
+        assert_noop!(
+            SUT::burn(Origin::signed(1), H256::repeat_byte(1)),
+            Error::<Test>::NotInRegistry
+        );
+    });
+}
+
+#[test]
+fn burn_through_nft_pallet_respects_registry() {
+    new_test_ext().execute_with(|| {
+        let (_, commodity_id) = mint_to_owner(false);
+
+        assert_noop!(
+            <pallet_nft::Module<Test>>::burn(Origin::signed(2), commodity_id),
+            Error::<Test>::OwnerCannotBurn
+        );
+    });
+}
+
+#[test]
+fn burn_through_nft_pallet_updates_registry() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, commodity_id) = mint_to_owner(true);
+
+        assert_ok!(<pallet_nft::Module<Test>>::burn(Origin::signed(2), commodity_id));
+
+        assert_eq!(assets_of(registry_id), vec![]);
+        assert_eq!(SUT::registry_total(registry_id), 0);
+        assert_eq!(SUT::registry_account_total(registry_id, 2), 0);
+        assert_eq!(SUT::attestation(commodity_id), None);
+        assert_ok!(SUT::destroy_registry(Origin::signed(1), registry_id));
+    });
+}
+
 #[test]
 fn create_registry_works() {
     new_test_ext().execute_with(|| {
-        let origin = Origin::signed(1);
+        let registry_id = create_registry_with(|_| {});
+
+        assert!(Registries::contains_key(registry_id));
+        assert_eq!(SUT::registry_admin(registry_id), Some(1));
+        assert_eq!(SUT::registry_total(registry_id), 0);
+    });
+}
+
+#[test]
+fn transfer_registry_admin_works() {
+    new_test_ext().execute_with(|| {
+        let registry_id = create_registry_with(|_| {});
+
+        assert_noop!(
+            SUT::transfer_registry_admin(Origin::signed(2), registry_id, 2),
+            Error::<Test>::NotRegistryAdmin
+        );
+        assert_ok!(SUT::transfer_registry_admin(Origin::signed(1), registry_id, 2));
+        assert_eq!(SUT::registry_admin(registry_id), Some(2));
+
+        // The previous admin lost their rights
+        assert_noop!(
+            SUT::freeze_minting(Origin::signed(1), registry_id),
+            Error::<Test>::NotRegistryAdmin
+        );
+    });
+}
+
+#[test]
+fn update_registry_works_while_empty() {
+    new_test_ext().execute_with(|| {
+        let registry_id = create_registry_with(|_| {});
+        let info = RegistryInfo {
+            owner_can_burn: true,
+            fields: vec![vec![0], vec![1]],
+            ..Default::default()
+        };
+
+        assert_ok!(SUT::update_registry(Origin::signed(1), registry_id, info.clone()));
+        assert_eq!(Registries::get(registry_id), info);
+    });
+}
+
+#[test]
+fn update_registry_with_tokens_fails() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, _) = mint_to_owner(false);
+
+        assert_noop!(
+            SUT::update_registry(Origin::signed(1), registry_id, RegistryInfo::default()),
+            Error::<Test>::RegistryNotEmpty
+        );
+    });
+}
+
+#[test]
+fn freeze_minting_works() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, _, mint_info) = setup_mint(false, vec![vec![2], vec![3]]);
+
+        assert_ok!(SUT::freeze_minting(Origin::signed(1), registry_id));
+        assert!(SUT::minting_frozen(registry_id));
+
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 2, registry_id, mint_info),
+            Error::<Test>::RegistryFrozen
+        );
+    });
+}
+
+#[test]
+fn destroy_registry_works() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, commodity_id) = mint_to_owner(false);
+
+        assert_noop!(
+            SUT::destroy_registry(Origin::signed(1), registry_id),
+            Error::<Test>::RegistryNotEmpty
+        );
+
+        assert_ok!(SUT::burn(Origin::signed(1), commodity_id));
+        assert_ok!(SUT::destroy_registry(Origin::signed(1), registry_id));
+
+        assert!(!Registries::contains_key(registry_id));
+        assert_eq!(SUT::registry_admin(registry_id), None);
+        assert_noop!(
+            SUT::destroy_registry(Origin::signed(1), registry_id),
+            Error::<Test>::RegistryDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn query_owner_and_assets_works() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, commodity_id) = mint_to_owner(false);
+        let asset_info = SUT::asset_info(&commodity_id).unwrap();
+
+        assert_eq!(SUT::owner_of(&commodity_id), Some(2));
+        assert_eq!(SUT::owner_of(&H256::repeat_byte(1)), None);
+        assert_eq!(SUT::assets_for_account(&2, None, 10), vec![(commodity_id, asset_info.clone())]);
+        assert_eq!(SUT::assets_for_account(&2, Some(commodity_id), 10), vec![]);
+        assert_eq!(SUT::assets_for_account(&1, None, 10), vec![]);
+        assert_eq!(asset_info.registry_id, registry_id);
+        assert_eq!(asset_info.values, vec![vec![2], vec![3]]);
+        assert_eq!(SUT::asset_info(&H256::repeat_byte(1)), None);
+        assert_eq!(SUT::registry_of(&commodity_id), Some(registry_id));
+        assert_eq!(SUT::registry_of(&H256::repeat_byte(1)), None);
+        assert_eq!(SUT::registry_info(registry_id).map(|info| info.fields.len()), Some(2));
+        assert_eq!(SUT::registry_info(registry_id + 1), None);
+    });
+}
+
+#[test]
+fn assets_in_registry_paginates_in_order_of_id() {
+    new_test_ext().execute_with(|| {
+        let ids: Vec<H256> = (1..=5).map(H256::repeat_byte).collect();
+        for id in ids.iter().rev() {
+            NftLists::<Test>::insert(0, id, ());
+        }
+        NftLists::<Test>::insert(1, H256::repeat_byte(6), ());
+
+        assert_eq!(SUT::assets_in_registry(0, None, 2), ids[..2].to_vec());
+        assert_eq!(SUT::assets_in_registry(0, Some(ids[1]), 2), ids[2..4].to_vec());
+        assert_eq!(SUT::assets_in_registry(0, Some(ids[3]), 2), ids[4..].to_vec());
+        assert_eq!(SUT::assets_in_registry(0, Some(ids[4]), 2), vec![]);
+    });
+}
+
+// Mint info with the given values, without any proofs.
+fn unproven_mint_info(values: Vec<Vec<u8>>) -> MintInfo<H256> {
+    MintInfo {
+        salts: vec![[1; 32]; values.len()],
+        values,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn mint_with_attester_signature_works() {
+    new_test_ext().execute_with(|| {
+        let attester = sr25519::Pair::from_seed(&[1; 32]);
+        let registry_id = create_registry_with(|info| info.verifier = VerifierKind::Attester(attester.public()));
+        let mut mint_info = unproven_mint_info(vec![vec![2], vec![3]]);
+        let leaves = vec![
+            SUT::leaf_hash(vec![0], vec![2], [1; 32]),
+            SUT::leaf_hash(vec![1], vec![3], [1; 32]),
+        ];
+        mint_info.signature = Some(attester.sign(&AttesterVerifier::message(registry_id, &2u64, &mint_info.anchor_id, mint_info.nonce, &leaves)));
+
+        // The attestation is for owner 2 only
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 3, registry_id, mint_info.clone()),
+            Error::<Test>::InvalidAttestation
+        );
+        // The attestation covers the anchor and nonce
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 2, registry_id, MintInfo { anchor_id: H256::repeat_byte(1), ..mint_info.clone() }),
+            Error::<Test>::InvalidAttestation
+        );
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 2, registry_id, MintInfo { nonce: 1, ..mint_info.clone() }),
+            Error::<Test>::InvalidAttestation
+        );
+        assert_ok!(SUT::mint(Origin::signed(1), 2, registry_id, mint_info.clone()));
+        assert_eq!(<pallet_nft::Module<Test>>::total_for_account(2), 1);
+
+        // An attestation mints a single token, another nonce needs another attestation
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 2, registry_id, mint_info.clone()),
+            Error::<Test>::ProofAlreadyUsed
+        );
+        mint_info.nonce = 1;
+        mint_info.signature = Some(attester.sign(&AttesterVerifier::message(registry_id, &2u64, &mint_info.anchor_id, mint_info.nonce, &leaves)));
+        assert_ok!(SUT::mint(Origin::signed(1), 2, registry_id, mint_info));
+        assert_eq!(<pallet_nft::Module<Test>>::total_for_account(2), 2);
+    });
+}
+
+#[test]
+fn mint_with_invalid_attester_signature_fails() {
+    new_test_ext().execute_with(|| {
+        let attester = sr25519::Pair::from_seed(&[1; 32]);
+        let other = sr25519::Pair::from_seed(&[2; 32]);
+        let registry_id = create_registry_with(|info| info.verifier = VerifierKind::Attester(attester.public()));
+        let mut mint_info = unproven_mint_info(vec![vec![2], vec![3]]);
+
+        // No signature
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 2, registry_id, mint_info.clone()),
+            Error::<Test>::InvalidAttestation
+        );
+
+        // Signed by someone other than the attester
+        let leaves = vec![
+            SUT::leaf_hash(vec![0], vec![2], [1; 32]),
+            SUT::leaf_hash(vec![1], vec![3], [1; 32]),
+        ];
+        mint_info.signature = Some(other.sign(&AttesterVerifier::message(registry_id, &2u64, &mint_info.anchor_id, mint_info.nonce, &leaves)));
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 2, registry_id, mint_info),
+            Error::<Test>::InvalidAttestation
+        );
+    });
+}
+
+#[test]
+fn mint_with_open_verifier_works() {
+    new_test_ext().execute_with(|| {
+        let registry_id = create_registry_with(|info| info.verifier = VerifierKind::Open);
+
+        assert_ok!(SUT::mint(Origin::signed(1), 2, registry_id, unproven_mint_info(vec![vec![2], vec![3]])));
+        assert_eq!(<pallet_nft::Module<Test>>::total_for_account(2), 1);
+
+        // Nothing was proven against the anchor of the mint info
+        let commodity_id = assets_of(registry_id)[0];
+        assert_eq!(SUT::asset_info(&commodity_id).and_then(|info| info.anchor_id), None);
+    });
+}
+
+#[test]
+fn create_registry_with_too_long_metadata_fails() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            SUT::create_registry(Origin::signed(1), RegistryInfo {
+                name: b"Too long name".to_vec(),
+                ..Default::default()
+            }),
+            Error::<Test>::NameTooLong
+        );
+        assert_noop!(
+            SUT::create_registry(Origin::signed(1), RegistryInfo {
+                symbol: b"LONG!".to_vec(),
+                ..Default::default()
+            }),
+            Error::<Test>::SymbolTooLong
+        );
+        assert_noop!(
+            SUT::create_registry(Origin::signed(1), RegistryInfo {
+                base_uri: vec![b'a'; 33],
+                ..Default::default()
+            }),
+            Error::<Test>::UriTooLong
+        );
+    });
+}
+
+#[test]
+fn set_registry_metadata_works_until_frozen() {
+    new_test_ext().execute_with(|| {
+        let registry_id = create_registry_with(|_| {});
+
+        assert_noop!(
+            SUT::set_registry_metadata(Origin::signed(2), registry_id, b"Deeds".to_vec(), b"DEED".to_vec(), vec![]),
+            Error::<Test>::NotRegistryAdmin
+        );
+        assert_ok!(SUT::set_registry_metadata(
+            Origin::signed(1), registry_id, b"Deeds".to_vec(), b"DEED".to_vec(), b"ipfs://deeds/".to_vec()));
+
+        let info = SUT::registry_info(registry_id).unwrap();
+        assert_eq!(info.name, b"Deeds".to_vec());
+        assert_eq!(info.symbol, b"DEED".to_vec());
+        assert_eq!(info.base_uri, b"ipfs://deeds/".to_vec());
+
+        assert_noop!(SUT::freeze_metadata(Origin::signed(2), registry_id), Error::<Test>::NotRegistryAdmin);
+        assert_ok!(SUT::freeze_metadata(Origin::signed(1), registry_id));
+        assert!(SUT::metadata_frozen(registry_id));
+        assert_noop!(
+            SUT::set_registry_metadata(Origin::signed(1), registry_id, vec![], vec![], vec![]),
+            Error::<Test>::MetadataFrozen
+        );
+        assert_noop!(
+            SUT::update_registry(Origin::signed(1), registry_id, RegistryInfo::default()),
+            Error::<Test>::MetadataFrozen
+        );
+    });
+}
+
+#[test]
+fn asset_uri_falls_back_to_base_uri() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, commodity_id) = mint_to_owner(false);
+        assert_eq!(SUT::asset_uri(&commodity_id), None);
+
+        assert_ok!(SUT::set_registry_metadata(Origin::signed(1), registry_id, vec![], vec![], b"uri/".to_vec()));
+        let mut expected = b"uri/".to_vec();
+        expected.extend(format!("{:x}", commodity_id).as_bytes());
+        assert_eq!(SUT::asset_uri(&commodity_id), Some(expected));
+
+        assert_noop!(
+            SUT::set_token_uri(Origin::signed(2), commodity_id, Some(b"mine".to_vec())),
+            Error::<Test>::NotRegistryAdmin
+        );
+        assert_noop!(
+            SUT::set_token_uri(Origin::signed(1), commodity_id, Some(vec![b'a'; 33])),
+            Error::<Test>::UriTooLong
+        );
+        assert_ok!(SUT::set_token_uri(Origin::signed(1), commodity_id, Some(b"token".to_vec())));
+        assert_eq!(SUT::token_uri(commodity_id), Some(b"token".to_vec()));
+        assert_eq!(SUT::asset_uri(&commodity_id), Some(b"token".to_vec()));
+
+        assert_ok!(SUT::freeze_metadata(Origin::signed(1), registry_id));
+        assert_noop!(
+            SUT::set_token_uri(Origin::signed(1), commodity_id, None),
+            Error::<Test>::MetadataFrozen
+        );
+
+        // Burning removes the token URI
+        assert_ok!(SUT::burn(Origin::signed(1), commodity_id));
+        assert_eq!(SUT::token_uri(commodity_id), None);
+    });
+}
+
+#[test]
+fn set_token_uri_outside_of_registry_fails() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, commodity_id) = mint_to_owner(false);
+        assert_ok!(SUT::burn(Origin::signed(1), commodity_id));
+        assert_noop!(
+            SUT::set_token_uri(Origin::signed(1), commodity_id, Some(b"token".to_vec())),
+            Error::<Test>::NotInRegistry
+        );
+
+        // Minted without going through the registry
+        let commodity_id = <pallet_nft::Module<Test> as Mintable>::mint(&1, AssetInfo { registry_id, ..Default::default() }).unwrap();
+        assert_noop!(
+            SUT::set_token_uri(Origin::signed(1), commodity_id, Some(b"token".to_vec())),
+            Error::<Test>::NotInRegistry
+        );
+        assert_eq!(SUT::token_uri(commodity_id), None);
+    });
+}
+
+#[test]
+fn mint_beyond_registry_max_supply_fails() {
+    new_test_ext().execute_with(|| {
+        let registry_id = create_registry_with(|info| {
+            info.verifier = VerifierKind::Open;
+            info.max_supply = Some(2);
+        });
+        let mint_info = unproven_mint_info(vec![vec![2], vec![3]]);
+
+        assert_ok!(SUT::mint(Origin::signed(1), 1, registry_id, mint_info.clone()));
+        assert_ok!(SUT::mint(Origin::signed(1), 2, registry_id, mint_info.clone()));
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 3, registry_id, mint_info.clone()),
+            Error::<Test>::RegistrySupplyExceeded
+        );
+
+        // Burning frees up supply
+        assert_ok!(SUT::burn(Origin::signed(1), assets_of(registry_id)[0]));
+        assert_ok!(SUT::mint(Origin::signed(1), 3, registry_id, mint_info));
+    });
+}
+
+#[test]
+fn registry_max_per_account_is_enforced_on_mint_and_transfer() {
+    new_test_ext().execute_with(|| {
+        let registry_id = create_registry_with(|info| {
+            info.verifier = VerifierKind::Open;
+            info.max_per_account = Some(1);
+        });
+        let mint_info = unproven_mint_info(vec![vec![2], vec![3]]);
+
+        assert_ok!(SUT::mint(Origin::signed(1), 2, registry_id, mint_info.clone()));
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 2, registry_id, mint_info.clone()),
+            Error::<Test>::TooManyTokensForAccount
+        );
+        assert_ok!(SUT::mint(Origin::signed(1), 3, registry_id, mint_info));
+        assert_eq!(SUT::registry_account_total(registry_id, 2), 1);
+
+        let ids = assets_of(registry_id);
+        let id_of_3 = ids.into_iter().find(|id| SUT::owner_of(id) == Some(3)).unwrap();
+        assert_noop!(
+            SUT::transfer(Origin::signed(3), 2, id_of_3),
+            Error::<Test>::TooManyTokensForAccount
+        );
+        // Transfers through pallet_nft are capped too
+        assert_noop!(
+            <pallet_nft::Module<Test> as Unique>::transfer(&2, &id_of_3),
+            Error::<Test>::TooManyTokensForAccount
+        );
+
+        assert_ok!(SUT::transfer(Origin::signed(3), 4, id_of_3));
+        assert_eq!(SUT::registry_account_total(registry_id, 3), 0);
+        assert_eq!(SUT::registry_account_total(registry_id, 4), 1);
+    });
+}
+
+#[test]
+fn admin_only_mint_policy_works() {
+    new_test_ext().execute_with(|| {
+        let registry_id = create_registry_with(|info| {
+            info.verifier = VerifierKind::Open;
+            info.mint_policy = MintPolicy::AdminOnly;
+        });
+        let mint_info = unproven_mint_info(vec![vec![2], vec![3]]);
+
+        assert_noop!(
+            SUT::mint(Origin::signed(2), 2, registry_id, mint_info.clone()),
+            Error::<Test>::MintRequiresAdmin
+        );
+        assert_ok!(SUT::mint(Origin::signed(1), 2, registry_id, mint_info));
+    });
+}
+
+#[test]
+fn allowlist_mint_policy_works() {
+    new_test_ext().execute_with(|| {
+        let registry_id = create_registry_with(|info| {
+            info.verifier = VerifierKind::Open;
+            info.mint_policy = MintPolicy::Allowlist;
+        });
+        let mint_info = unproven_mint_info(vec![vec![2], vec![3]]);
+
+        assert_noop!(
+            SUT::mint(Origin::signed(2), 2, registry_id, mint_info.clone()),
+            Error::<Test>::NotAllowedMinter
+        );
+
+        assert_noop!(SUT::add_minter(Origin::signed(2), registry_id, 2), Error::<Test>::NotRegistryAdmin);
+        assert_ok!(SUT::add_minter(Origin::signed(1), registry_id, 2));
+        assert!(SUT::is_minter(registry_id, 2));
+        assert_ok!(SUT::mint(Origin::signed(2), 3, registry_id, mint_info.clone()));
+
+        assert_ok!(SUT::remove_minter(Origin::signed(1), registry_id, 2));
+        assert_noop!(
+            SUT::mint(Origin::signed(2), 3, registry_id, mint_info),
+            Error::<Test>::NotAllowedMinter
+        );
+    });
+}
+
+#[test]
+fn anchor_owner_to_self_mint_policy_works() {
+    new_test_ext().execute_with(|| {
+        // The document is anchored by account 1
+        let (registry_id, _, mint_info) = setup_mint(false, vec![vec![2], vec![3]]);
+        let info = RegistryInfo {
+            mint_policy: MintPolicy::AnchorOwnerToSelf,
+            ..SUT::registry_info(registry_id).unwrap()
+        };
+        assert_ok!(SUT::update_registry(Origin::signed(1), registry_id, info));
+
+        assert_noop!(
+            SUT::mint(Origin::signed(2), 2, registry_id, mint_info.clone()),
+            Error::<Test>::NotAnchorOwner
+        );
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 2, registry_id, mint_info.clone()),
+            Error::<Test>::CanOnlyMintToSelf
+        );
+        assert_ok!(SUT::mint(Origin::signed(1), 1, registry_id, mint_info));
+    });
+}
+
+#[test]
+fn anchor_owner_to_self_mint_policy_requires_anchor_verifier() {
+    new_test_ext().execute_with(|| {
+        let info = RegistryInfo {
+            fields: vec![vec![0], vec![1]],
+            verifier: VerifierKind::Open,
+            mint_policy: MintPolicy::AnchorOwnerToSelf,
+            ..Default::default()
+        };
+        assert_noop!(
+            SUT::create_registry(Origin::signed(1), info.clone()),
+            Error::<Test>::MintPolicyRequiresAnchors
+        );
+
+        let registry_id = create_registry_with(|info| info.verifier = VerifierKind::Open);
+        assert_noop!(
+            SUT::update_registry(Origin::signed(1), registry_id, info),
+            Error::<Test>::MintPolicyRequiresAnchors
+        );
+    });
+}
+
+// Mint a token to account 2 in an open registry with the given transfer policy,
+// administered by account 1.
+fn mint_with_transfer_policy(transfer_policy: TransferPolicy) -> (RegistryId, H256) {
+    let registry_id = create_registry_with(|info| {
+        info.verifier = VerifierKind::Open;
+        info.transfer_policy = transfer_policy;
+    });
+    assert_ok!(SUT::mint(Origin::signed(1), 2, registry_id, unproven_mint_info(vec![vec![2], vec![3]])));
+    (registry_id, assets_of(registry_id)[0])
+}
+
+#[test]
+fn non_transferable_tokens_cant_be_transferred() {
+    new_test_ext().execute_with(|| {
+        let (_, commodity_id) = mint_with_transfer_policy(TransferPolicy::NonTransferable);
+
+        assert_noop!(
+            SUT::transfer(Origin::signed(2), 3, commodity_id),
+            Error::<Test>::NonTransferable
+        );
+        assert_noop!(
+            <pallet_nft::Module<Test> as Unique>::transfer(&3, &commodity_id),
+            Error::<Test>::NonTransferable
+        );
+
+        // The admin can still burn them
+        assert_ok!(SUT::burn(Origin::signed(1), commodity_id));
+    });
+}
+
+#[test]
+fn admin_approved_transfers_work_once() {
+    new_test_ext().execute_with(|| {
+        let (_, commodity_id) = mint_with_transfer_policy(TransferPolicy::AdminApproved);
+
+        assert_noop!(
+            SUT::transfer(Origin::signed(2), 3, commodity_id),
+            Error::<Test>::TransferNotApproved
+        );
+        assert_noop!(
+            SUT::approve_transfer(Origin::signed(2), commodity_id, Some(3)),
+            Error::<Test>::NotRegistryAdmin
+        );
+
+        assert_ok!(SUT::approve_transfer(Origin::signed(1), commodity_id, Some(3)));
+        assert_noop!(
+            SUT::transfer(Origin::signed(2), 4, commodity_id),
+            Error::<Test>::TransferNotApproved
+        );
+        assert_ok!(SUT::transfer(Origin::signed(2), 3, commodity_id));
+        assert_eq!(SUT::transfer_approval(commodity_id), None);
+
+        assert_noop!(
+            SUT::transfer(Origin::signed(3), 2, commodity_id),
+            Error::<Test>::TransferNotApproved
+        );
+    });
+}
+
+#[test]
+fn allowlist_transfers_only_go_to_recipients() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, commodity_id) = mint_with_transfer_policy(TransferPolicy::Allowlist);
+
+        assert_noop!(
+            SUT::transfer(Origin::signed(2), 3, commodity_id),
+            Error::<Test>::RecipientNotAllowed
+        );
+
+        assert_noop!(SUT::add_recipient(Origin::signed(2), registry_id, 3), Error::<Test>::NotRegistryAdmin);
+        assert_ok!(SUT::add_recipient(Origin::signed(1), registry_id, 3));
+        assert_ok!(SUT::transfer(Origin::signed(2), 3, commodity_id));
+
+        assert_ok!(SUT::remove_recipient(Origin::signed(1), registry_id, 3));
+        assert!(!SUT::is_recipient(registry_id, 3));
+    });
+}
+
+// Make the registry revocable, while it is still empty.
+fn make_revocable(registry_id: RegistryId) {
+    let info = RegistryInfo {
+        revocable: true,
+        ..SUT::registry_info(registry_id).unwrap()
+    };
+    assert_ok!(SUT::update_registry(Origin::signed(1), registry_id, info));
+}
+
+#[test]
+fn revoke_requires_revocable_registry() {
+    new_test_ext().execute_with(|| {
+        let (_, commodity_id) = mint_to_owner(false);
+
+        assert_noop!(
+            SUT::revoke(Origin::signed(1), commodity_id, false),
+            Error::<Test>::NotRevocable
+        );
+    });
+}
+
+#[test]
+fn revoked_anchor_invalidates_tokens() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, anchor_id, mint_info) = setup_mint(false, vec![vec![2], vec![3]]);
+        assert_ok!(SUT::mint(Origin::signed(1), 2, registry_id, mint_info.clone()));
+        let commodity_id = assets_of(registry_id)[0];
+        assert!(SUT::is_live(&commodity_id));
+
+        assert_ok!(<pallet_anchor::Module<Test>>::revoke_anchor(Origin::signed(1), anchor_id));
+        assert!(!SUT::is_live(&commodity_id));
+
+        // No more tokens can be minted against the anchor
+        let other_registry_id = create_registry_with(|info| info.verifier = VerifierKind::MerkleAnchor);
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 2, other_registry_id, mint_info),
+            Error::<Test>::AnchorRevoked
+        );
+    });
+}
+
+#[test]
+fn revoke_by_admin_marks_token_revoked() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, anchor_id, mint_info) = setup_mint(false, vec![vec![2], vec![3]]);
+        make_revocable(registry_id);
+        assert_ok!(SUT::mint(Origin::signed(1), 2, registry_id, mint_info));
+        let commodity_id = assets_of(registry_id)[0];
+        assert_eq!(SUT::asset_info(&commodity_id).and_then(|info| info.anchor_id), Some(anchor_id));
+        assert!(SUT::is_live(&commodity_id));
+
+        assert_noop!(
+            SUT::revoke(Origin::signed(2), commodity_id, false),
+            Error::<Test>::NotRevoker
+        );
+        assert_ok!(SUT::revoke(Origin::signed(1), commodity_id, false));
+        assert!(SUT::is_revoked(commodity_id));
+        assert!(!SUT::is_live(&commodity_id));
+        assert_noop!(
+            SUT::revoke(Origin::signed(1), commodity_id, false),
+            Error::<Test>::AlreadyRevoked
+        );
+
+        // Revoked tokens can't be transferred
+        assert_noop!(
+            SUT::transfer(Origin::signed(2), 3, commodity_id),
+            Error::<Test>::TokenRevoked
+        );
+    });
+}
+
+#[test]
+fn revoke_by_anchor_owner_can_burn() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, _, mint_info) = setup_mint(false, vec![vec![2], vec![3]]);
+        make_revocable(registry_id);
+        assert_ok!(SUT::mint(Origin::signed(1), 2, registry_id, mint_info));
+        let commodity_id = assets_of(registry_id)[0];
+
+        // The anchor owner need not be the registry admin
+        assert_ok!(SUT::transfer_registry_admin(Origin::signed(1), registry_id, 3));
+        assert_ok!(SUT::revoke(Origin::signed(1), commodity_id, true));
+
+        assert_eq!(SUT::owner_of(&commodity_id), None);
+        assert_eq!(SUT::registry_total(registry_id), 0);
+        assert!(!SUT::is_revoked(commodity_id));
+        assert!(!SUT::is_live(&commodity_id));
+    });
+}
+
+#[test]
+fn revoke_by_owner_of_unproven_anchor_fails() {
+    new_test_ext().execute_with(|| {
+        let registry_id = create_registry_with(|info| info.verifier = VerifierKind::Open);
+        make_revocable(registry_id);
+
+        // The anchor of account 3 is referenced, but nothing is proven against it
+        let pre_image = <Test as frame_system::Trait>::Hashing::hash_of(&0);
+        let anchor_id = (pre_image).using_encoded(<Test as frame_system::Trait>::Hashing::hash);
+        assert_ok!(<pallet_anchor::Module<Test>>::commit(Origin::signed(3), pre_image, H256::zero(), H256::zero(), 100));
+        let mint_info = MintInfo { anchor_id, ..unproven_mint_info(vec![vec![2], vec![3]]) };
+        assert_ok!(SUT::mint(Origin::signed(3), 3, registry_id, mint_info));
+        let commodity_id = assets_of(registry_id)[0];
+
+        assert_noop!(
+            SUT::revoke(Origin::signed(3), commodity_id, true),
+            Error::<Test>::NotRevoker
+        );
+    });
+}
+
+#[test]
+fn mint_against_expired_anchor_fails() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, _, mint_info) = setup_mint(false, vec![vec![2], vec![3]]);
+
+        // The anchor expires after block 100
+        frame_system::Module::<Test>::set_block_number(101);
+        assert_noop!(
+            SUT::mint(Origin::signed(1), 2, registry_id, mint_info),
+            Error::<Test>::AnchorExpired
+        );
+    });
+}
+
+#[test]
+fn reattest_extends_validity() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, anchor_id, mint_info) = setup_mint(false, vec![vec![2], vec![3]]);
+        assert_ok!(SUT::mint(Origin::signed(1), 2, registry_id, mint_info.clone()));
+        let commodity_id = assets_of(registry_id)[0];
+        assert_eq!(SUT::attestation(commodity_id), Some(Attestation { anchor_id, valid_until: 100 }));
+
+        // Anchor the same document again with a later expiry
+        let pre_image = <Test as frame_system::Trait>::Hashing::hash_of(&1);
+        let new_anchor_id = (pre_image).using_encoded(<Test as frame_system::Trait>::Hashing::hash);
+        let doc_root = <pallet_anchor::Module<Test>>::get_anchor(anchor_id).unwrap().doc_root;
+        assert_ok!(<pallet_anchor::Module<Test>>::commit(Origin::signed(1), pre_image, doc_root, H256::zero(), 200));
+
+        frame_system::Module::<Test>::set_block_number(150);
+        assert!(!SUT::is_live(&commodity_id));
+
+        let new_mint_info = MintInfo { anchor_id: new_anchor_id, ..mint_info.clone() };
+        // Only the owner can reattest
+        assert_noop!(
+            SUT::reattest(Origin::signed(1), commodity_id, new_mint_info.clone()),
+            Error::<Test>::NotCommodityOwner
+        );
+        // An expired anchor can't be used
+        assert_noop!(
+            SUT::reattest(Origin::signed(2), commodity_id, mint_info),
+            Error::<Test>::AnchorExpired
+        );
+        // The values must be those of the token
+        assert_noop!(
+            SUT::reattest(Origin::signed(2), commodity_id, MintInfo { values: vec![vec![4], vec![3]], ..new_mint_info.clone() }),
+            Error::<Test>::InvalidMintingValues
+        );
+
+        assert_ok!(SUT::reattest(Origin::signed(2), commodity_id, new_mint_info));
+        assert_eq!(SUT::attestation(commodity_id), Some(Attestation { anchor_id: new_anchor_id, valid_until: 200 }));
+        // The asset info keeps the anchor of the mint
+        assert_eq!(SUT::asset_info(&commodity_id).unwrap().anchor_id, Some(anchor_id));
+        assert!(SUT::is_live(&commodity_id));
+
+        frame_system::Module::<Test>::set_block_number(201);
+        assert!(!SUT::is_live(&commodity_id));
+    });
+}
+
+#[test]
+fn reattest_with_earlier_expiry_fails() {
+    new_test_ext().execute_with(|| {
+        let (registry_id, anchor_id, mint_info) = setup_mint(false, vec![vec![2], vec![3]]);
+        assert_ok!(SUT::mint(Origin::signed(1), 2, registry_id, mint_info.clone()));
+        let commodity_id = assets_of(registry_id)[0];
+
+        // Anchor the same document again, expiring before the current attestation
+        let pre_image = <Test as frame_system::Trait>::Hashing::hash_of(&1);
+        let new_anchor_id = (pre_image).using_encoded(<Test as frame_system::Trait>::Hashing::hash);
+        let doc_root = <pallet_anchor::Module<Test>>::get_anchor(anchor_id).unwrap().doc_root;
+        assert_ok!(<pallet_anchor::Module<Test>>::commit(Origin::signed(1), pre_image, doc_root, H256::zero(), 50));
+
+        assert_noop!(
+            SUT::reattest(Origin::signed(2), commodity_id, MintInfo { anchor_id: new_anchor_id, ..mint_info }),
+            Error::<Test>::ValidityNotExtended
+        );
+        assert_eq!(SUT::attestation(commodity_id), Some(Attestation { anchor_id, valid_until: 100 }));
+    });
+}
+
+#[test]
+fn reattest_requires_anchor_verified_registry() {
+    new_test_ext().execute_with(|| {
+        let registry_id = create_registry_with(|info| info.verifier = VerifierKind::Open);
+        assert_ok!(SUT::mint(Origin::signed(1), 1, registry_id, unproven_mint_info(vec![vec![2], vec![3]])));
+        let commodity_id = assets_of(registry_id)[0];
+        assert_eq!(SUT::attestation(commodity_id), None);
+
+        assert_noop!(
+            SUT::reattest(Origin::signed(1), commodity_id, unproven_mint_info(vec![vec![2], vec![3]])),
+            Error::<Test>::NotAnchorVerified
+        );
     });
 }
@@ -1,7 +1,13 @@
 use crate::proofs::Proof;
+use crate::verifiers::VerifierKind;
 use pallet_nft::InRegistry;
 use frame_support::dispatch;
 use codec::{Decode, Encode};
+use sp_core::sr25519;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
 // Registries are identified using a nonce in storage
 pub type RegistryId = u128;
@@ -9,12 +15,15 @@ pub type RegistryId = u128;
 // A vector of bytes, conveniently named like it is in Solidity
 pub type bytes = Vec<u8>;
 
+// A random salt of a document tree leaf
+pub type Salt = [u8; 32];
+
 // A convenience rename from pallet_nft's id type
 pub type AssetId<T> = pallet_nft::CommodityId<T>;
 
 // Metadata for a registry instance
-#[derive(Encode, Decode, Clone, PartialEq, Default)]
-#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 /// Metadata for an instance of a registry.
 pub struct RegistryInfo {
     /// A configuration option that will enable a user to burn their own tokens
@@ -23,28 +32,108 @@ pub struct RegistryInfo {
     /// Names of fields required to be provided for verification during a [mint].
     /// These *MUST* be compact encoded.
     pub fields: Vec<bytes>,
+    /// The verifier that checks the values of a [mint].
+    pub verifier: VerifierKind,
+    /// Whether the registry admin or the anchor owner of a token may revoke it.
+    pub revocable: bool,
+    /// Who may mint in the registry.
+    pub mint_policy: MintPolicy,
+    /// Whether and to whom tokens of the registry may be transferred.
+    pub transfer_policy: TransferPolicy,
+    /// The maximum number of tokens the registry may hold at once, if limited.
+    pub max_supply: Option<u128>,
+    /// The maximum number of tokens of the registry that any single account may own,
+    /// if limited.
+    pub max_per_account: Option<u64>,
+    /// Display name of the registry, bounded by `MaxNameLength`.
+    pub name: bytes,
+    /// Short ticker symbol of the registry, bounded by `MaxSymbolLength`.
+    pub symbol: bytes,
+    /// URI that the URI of a token without its own token URI is relative to,
+    /// bounded by `MaxUriLength`.
+    pub base_uri: bytes,
+}
+
+/// Who may mint in a registry, on top of the verification of the mint.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum MintPolicy {
+    /// Any account may mint to any account.
+    Anyone,
+    /// Only the registry admin may mint.
+    AdminOnly,
+    /// Only accounts on the allowlist of minters of the registry may mint.
+    Allowlist,
+    /// Only the owner of the anchor the mint is proven against may mint, and only
+    /// to itself. Requires a registry that verifies mints against anchors.
+    AnchorOwnerToSelf,
+}
+
+impl Default for MintPolicy {
+    fn default() -> Self {
+        MintPolicy::Anyone
+    }
+}
+
+/// Whether and to whom the tokens of a registry may be transferred.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TransferPolicy {
+    /// Tokens may be transferred to any account.
+    Free,
+    /// Tokens can't be transferred (soulbound). They may still be burned.
+    NonTransferable,
+    /// Each transfer must be approved by the registry admin for its destination.
+    AdminApproved,
+    /// Tokens may only be transferred to accounts on the allowlist of recipients
+    /// of the registry.
+    Allowlist,
 }
 
-/// All data for an instance of an NFT.
-#[derive(Encode, Decode, Clone, PartialEq, Eq, Default)]
-#[cfg_attr(feature = "std", derive(Debug))]
-pub struct AssetInfo {
+impl Default for TransferPolicy {
+    fn default() -> Self {
+        TransferPolicy::Free
+    }
+}
+
+/// All data for an instance of an NFT, as verified when it was minted.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetInfo<Hash> {
+    /// The registry the asset was minted in.
     pub registry_id: RegistryId,
-    // TODO: Other generic fields ..
+    /// The anchor of the document the values were proven against at the time of the
+    /// mint, for registries that verify mints against anchors. Reattesting a token
+    /// doesn't change it; the anchor it was last proven against is in its attestation.
+    pub anchor_id: Option<Hash>,
+    /// Names of the fields of the registry at the time of the mint.
+    pub fields: Vec<bytes>,
+    /// The verified values of the fields, in the same order.
+    pub values: Vec<bytes>,
 }
 
 // Registry id must be a field within the data, because an assets id
-// is a hash of its content, and its registry is part of its uniquely
+// is derived from its content, and its registry is part of its uniquely
 // identifying information.
-impl InRegistry for AssetInfo {
+impl<Hash> InRegistry for AssetInfo<Hash> {
     fn registry_id(&self) -> RegistryId {
         self.registry_id
     }
 }
 
+/// The anchor a token was last proven against, which bounds its validity.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Attestation<Hash, BlockNumber> {
+    /// The anchor of the document the values of the token were proven against.
+    pub anchor_id: Hash,
+    /// The expiration block of the anchor, after which the token is no longer valid
+    /// unless it is reattested.
+    pub valid_until: BlockNumber,
+}
+
 /// Data needed to provide proofs during a mint.
-#[derive(Encode, Decode, Clone, PartialEq, Default)]
-#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug)]
 pub struct MintInfo<Hash> {
     /// Unique ID to an anchor document.
     pub anchor_id: Hash,
@@ -52,8 +141,19 @@ pub struct MintInfo<Hash> {
     /// merkelized with its proof will be the root hash of the anchor
     /// document when valid.
     pub proofs: Vec<Proof>,
+    /// Static proofs used to compute the document root, in the order
+    /// [basic data root, zk data root, signature root].
+    pub static_proofs: [Hash; 3],
     /// Values correspond with fields specified by a registry.
     pub values: Vec<bytes>,
+    /// Salts correspond with values, and are hashed into their leaves so
+    /// that low entropy values can't be guessed from the leaf hashes.
+    pub salts: Vec<Salt>,
+    /// Signature of the registry's attester, for registries verified by an attester.
+    pub signature: Option<sr25519::Signature>,
+    /// Nonce chosen by the attester and covered by its signature, so the same values
+    /// can be attested more than once.
+    pub nonce: u64,
 }
 
 /// A general interface for registries that require some sort of verification to mint their
@@ -67,8 +167,8 @@ pub trait VerifierRegistry {
     type RegistryInfo;
     /// The id type of an NFT.
     type AssetId;
-    /// The data that defines the NFT held by a registry. Asset info must contain its
-    /// associated registry id.
+    /// The data that defines the NFT held by a registry, built from the verified mint
+    /// info. Asset info must contain its associated registry id.
     type AssetInfo: InRegistry;
     /// All data necessary to determine if a requested mint is valid or not.
     type MintInfo;
@@ -76,10 +176,10 @@ pub trait VerifierRegistry {
     /// Create a new instance of a registry with the associated registry info.
     fn create_registry(info: &Self::RegistryInfo) -> Result<Self::RegistryId, dispatch::DispatchError>;
 
-    /// Use the mint info to verify whether the mint is a valid action.
-    /// If so, use the asset info to mint an asset.
+    /// Use the mint info to verify whether a mint into the registry is a valid action.
+    /// If so, mint an asset with the asset info built from the verified mint info.
     fn mint(owner_account: Self::AccountId,
-            asset_info: Self::AssetInfo,
+            registry_id: Self::RegistryId,
             mint_info: Self::MintInfo,
     ) -> Result<Self::AssetId, dispatch::DispatchError>;
 }
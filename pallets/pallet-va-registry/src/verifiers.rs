@@ -0,0 +1,140 @@
+use crate::{proofs, Error, MintInfo, Module, RegistryId, Trait};
+use codec::{Decode, Encode};
+use frame_support::{dispatch, ensure};
+use sp_core::sr25519;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// A backend that decides whether a mint into a registry is verified.
+pub trait MintVerifier<T: Trait> {
+    /// Verify the `leaves` of the salted field values of a mint to `owner`, using
+    /// the rest of the mint info as evidence.
+    fn verify(&self,
+              registry_id: RegistryId,
+              owner: &T::AccountId,
+              leaves: &[T::Hash],
+              mint_info: &MintInfo<T::Hash>,
+    ) -> dispatch::DispatchResult;
+}
+
+/// The verifier selected by a registry for its mints.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum VerifierKind {
+    /// Merkle proofs of the leaves against an anchored document root. See [MerkleAnchorVerifier].
+    MerkleAnchor,
+    /// A signature of the given attester. See [AttesterVerifier].
+    Attester(sr25519::Public),
+    /// Every mint is verified. See [OpenVerifier].
+    Open,
+}
+
+impl Default for VerifierKind {
+    fn default() -> Self {
+        VerifierKind::MerkleAnchor
+    }
+}
+
+impl<T: Trait> MintVerifier<T> for VerifierKind {
+    fn verify(&self,
+              registry_id: RegistryId,
+              owner: &T::AccountId,
+              leaves: &[T::Hash],
+              mint_info: &MintInfo<T::Hash>,
+    ) -> dispatch::DispatchResult {
+        match self {
+            VerifierKind::MerkleAnchor =>
+                MintVerifier::<T>::verify(&MerkleAnchorVerifier, registry_id, owner, leaves, mint_info),
+            VerifierKind::Attester(attester) =>
+                MintVerifier::<T>::verify(&AttesterVerifier(attester.clone()), registry_id, owner, leaves, mint_info),
+            VerifierKind::Open =>
+                MintVerifier::<T>::verify(&OpenVerifier, registry_id, owner, leaves, mint_info),
+        }
+    }
+}
+
+/// Verifies that every leaf is proven against the root of the anchored document
+/// referenced by the mint info.
+///
+/// Proofs are given in the same order as the values, so each proof must be for
+/// the leaf of its corresponding field/value pair.
+pub struct MerkleAnchorVerifier;
+
+impl<T: Trait> MintVerifier<T> for MerkleAnchorVerifier {
+    fn verify(&self,
+              _registry_id: RegistryId,
+              _owner: &T::AccountId,
+              leaves: &[T::Hash],
+              mint_info: &MintInfo<T::Hash>,
+    ) -> dispatch::DispatchResult {
+        let doc_root = <Module<T>>::get_document_root(mint_info.anchor_id)?;
+
+        ensure!(
+            mint_info.proofs.len() == leaves.len() &&
+            mint_info.proofs.iter().zip(leaves.iter()).all(|(proof, leaf)| proof.leaf_hash == *leaf),
+            Error::<T>::InvalidProofs
+        );
+
+        // Verify the proofs against document root
+        ensure!(
+            proofs::validate_proofs(doc_root, &mint_info.proofs, mint_info.static_proofs),
+            Error::<T>::InvalidProofs
+        );
+
+        Ok(())
+    }
+}
+
+/// Verifies that the attester signed the mint with its sr25519 key.
+///
+/// The signed message is the SCALE encoding of `(registry_id, owner, anchor_id, nonce, leaves)`,
+/// so an attestation can't be used for another registry, owner, anchor or set of values,
+/// and the nonce tells apart attestations of the same values.
+pub struct AttesterVerifier(pub sr25519::Public);
+
+impl AttesterVerifier {
+    /// The message an attester signs to attest a mint.
+    pub fn message<AccountId: Encode, Hash: Encode>(registry_id: RegistryId,
+                                                    owner: &AccountId,
+                                                    anchor_id: &Hash,
+                                                    nonce: u64,
+                                                    leaves: &[Hash],
+    ) -> Vec<u8> {
+        (registry_id, owner, anchor_id, nonce, leaves).encode()
+    }
+}
+
+impl<T: Trait> MintVerifier<T> for AttesterVerifier {
+    fn verify(&self,
+              registry_id: RegistryId,
+              owner: &T::AccountId,
+              leaves: &[T::Hash],
+              mint_info: &MintInfo<T::Hash>,
+    ) -> dispatch::DispatchResult {
+        let signature = mint_info.signature.as_ref().ok_or(Error::<T>::InvalidAttestation)?;
+        let message = Self::message(registry_id, owner, &mint_info.anchor_id, mint_info.nonce, leaves);
+
+        ensure!(
+            sp_io::crypto::sr25519_verify(signature, &message, &self.0),
+            Error::<T>::InvalidAttestation
+        );
+
+        Ok(())
+    }
+}
+
+/// Verifies every mint. Only meant for testing.
+pub struct OpenVerifier;
+
+impl<T: Trait> MintVerifier<T> for OpenVerifier {
+    fn verify(&self,
+              _registry_id: RegistryId,
+              _owner: &T::AccountId,
+              _leaves: &[T::Hash],
+              _mint_info: &MintInfo<T::Hash>,
+    ) -> dispatch::DispatchResult {
+        Ok(())
+    }
+}
@@ -41,6 +41,9 @@ pub use frame_support::{
 /// Import the template pallet.
 pub use template;
 
+/// Types of the VA registry pallet, as used by its runtime api.
+pub use pallet_va_registry::{AssetInfo, Attestation, RegistryId, RegistryInfo};
+
 /// An index to a block.
 pub type BlockNumber = u32;
 
@@ -95,7 +98,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("node-template"),
 	impl_name: create_runtime_str!("node-template"),
 	authoring_version: 1,
-	spec_version: 1,
+	spec_version: 2,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -262,6 +265,50 @@ impl template::Trait for Runtime {
 	type Event = Event;
 }
 
+parameter_types! {
+	/// Anchor ids stay reserved for their pre-commit owner for a day.
+	pub const PreCommitExpiration: BlockNumber = DAYS;
+}
+
+impl pallet_anchor::Trait for Runtime {
+	type Event = Event;
+	type PreCommitExpiration = PreCommitExpiration;
+}
+
+parameter_types! {
+	pub const CommodityLimit: u128 = 1_000_000_000;
+	pub const UserCommodityLimit: u64 = 10_000;
+}
+
+impl pallet_nft::Trait for Runtime {
+	type Event = Event;
+	/// Only root can mint directly, without the verification of a registry.
+	type CommodityAdmin = frame_system::EnsureRoot<AccountId>;
+	/// Assets are minted through the registries of the VA registry pallet.
+	type CommodityInfo = pallet_va_registry::AssetInfo<Hash>;
+	type CommodityLimit = CommodityLimit;
+	type UserCommodityLimit = UserCommodityLimit;
+	/// Registries may mint any number of assets with identical info.
+	type IdStrategy = pallet_nft::ContentHashWithNonce;
+	/// Registries enforce their own limits on transfers.
+	type TransferHook = VaRegistry;
+	type BurnHook = VaRegistry;
+}
+
+parameter_types! {
+	pub const MaxNameLength: u32 = 64;
+	pub const MaxSymbolLength: u32 = 16;
+	pub const MaxUriLength: u32 = 256;
+}
+
+impl pallet_va_registry::Trait for Runtime {
+	type Event = Event;
+	type Anchors = Anchor;
+	type MaxNameLength = MaxNameLength;
+	type MaxSymbolLength = MaxSymbolLength;
+	type MaxUriLength = MaxUriLength;
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub enum Runtime where
@@ -279,6 +326,9 @@ construct_runtime!(
 		Sudo: pallet_sudo::{Module, Call, Config<T>, Storage, Event<T>},
 		// Include the custom logic from the template pallet in the runtime.
 		TemplateModule: template::{Module, Call, Storage, Event<T>},
+		Anchor: pallet_anchor::{Module, Call, Storage, Event<T>},
+		Nft: pallet_nft::{Module, Call, Config, Storage, Event<T>},
+		VaRegistry: pallet_va_registry::{Module, Call, Storage, Event<T>},
 	}
 );
 
@@ -438,4 +488,64 @@ impl_runtime_apis! {
 			TransactionPayment::query_info(uxt, len)
 		}
 	}
+
+	impl pallet_va_registry_rpc_runtime_api::VaRegistryApi<
+		Block,
+		AccountId,
+		Hash,
+		pallet_va_registry::AssetInfo<Hash>,
+		pallet_va_registry::Attestation<Hash, BlockNumber>,
+		pallet_va_registry::RegistryId,
+		pallet_va_registry::RegistryInfo,
+	> for Runtime {
+		fn owner_of(asset_id: Hash) -> Option<AccountId> {
+			VaRegistry::owner_of(&asset_id)
+		}
+
+		fn asset_info(asset_id: Hash) -> Option<pallet_va_registry::AssetInfo<Hash>> {
+			VaRegistry::asset_info(&asset_id)
+		}
+
+		fn attestation(asset_id: Hash) -> Option<pallet_va_registry::Attestation<Hash, BlockNumber>> {
+			VaRegistry::attestation(asset_id)
+		}
+
+		fn asset_uri(asset_id: Hash) -> Option<Vec<u8>> {
+			VaRegistry::asset_uri(&asset_id)
+		}
+
+		fn is_live(asset_id: Hash) -> bool {
+			VaRegistry::is_live(&asset_id)
+		}
+
+		fn assets_for_account(
+			account: AccountId,
+			start_after: Option<Hash>,
+			limit: u32,
+		) -> Vec<(Hash, pallet_va_registry::AssetInfo<Hash>)> {
+			VaRegistry::assets_for_account(&account, start_after, limit)
+		}
+
+		fn registry_info(
+			registry_id: pallet_va_registry::RegistryId,
+		) -> Option<pallet_va_registry::RegistryInfo> {
+			VaRegistry::registry_info(registry_id)
+		}
+
+		fn assets_in_registry(
+			registry_id: pallet_va_registry::RegistryId,
+			start_after: Option<Hash>,
+			limit: u32,
+		) -> Vec<Hash> {
+			VaRegistry::assets_in_registry(registry_id, start_after, limit)
+		}
+
+		fn total() -> u128 {
+			Nft::total()
+		}
+
+		fn burned() -> u128 {
+			Nft::burned()
+		}
+	}
 }